@@ -0,0 +1,113 @@
+use serde::Serialize;
+#[cfg(feature = "wasm")]
+use tsify::Tsify;
+use ttf_parser::{RawFace, Tag};
+
+use crate::directory::face_offset;
+
+/// A magic number used to calculate `head.checkSumAdjustment`.
+const CHECKSUM_MAGIC: u32 = 0xB1B0AFBA;
+
+/// A table whose stored checksum doesn't match its data.
+#[derive(Clone, Debug, Serialize)]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi))]
+pub struct ChecksumMismatch {
+    /// Table identifier.
+    pub tag: String,
+
+    /// Checksum stored in the table record.
+    pub stored: u32,
+
+    /// Checksum calculated from the table data.
+    ///
+    /// `None` when the table record points outside of the font data.
+    pub computed: Option<u32>,
+}
+
+/// A result of the checksums verification.
+#[derive(Clone, Debug, Serialize)]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi))]
+#[serde(rename_all = "camelCase")]
+pub struct ChecksumReport {
+    /// Checks that all table checksums and `head.checkSumAdjustment` are valid.
+    pub is_valid: bool,
+
+    /// Tables with invalid checksums.
+    pub mismatches: Vec<ChecksumMismatch>,
+
+    /// `checkSumAdjustment` stored in the `head` table.
+    pub check_sum_adjustment: Option<u32>,
+
+    /// `checkSumAdjustment` calculated from the font data.
+    pub expected_check_sum_adjustment: Option<u32>,
+}
+
+impl ChecksumReport {
+    /// Verifies the table checksums of a face at the specified collection index.
+    pub fn new(raw_face: &RawFace, index: u32) -> Self {
+        let data = raw_face.data;
+        let mut mismatches = Vec::new();
+        let mut check_sum_adjustment = None;
+        let mut total = face_offset(data, index)
+            .and_then(|offset| {
+                let start = offset as usize;
+                let length = 12 + 16 * raw_face.table_records.len() as usize;
+                data.get(start..start.checked_add(length)?)
+            })
+            .map(checksum);
+
+        for record in raw_face.table_records {
+            let start = record.offset as usize;
+            let table = start
+                .checked_add(record.length as usize)
+                .and_then(|end| data.get(start..end));
+
+            let computed = table.map(|table| {
+                if record.tag == Tag::from_bytes(b"head") && table.len() >= 12 {
+                    check_sum_adjustment = Some(u32::from_be_bytes([
+                        table[8], table[9], table[10], table[11],
+                    ]));
+                    // `checkSumAdjustment` is treated as zero.
+                    checksum(table).wrapping_sub(check_sum_adjustment.unwrap_or_default())
+                } else {
+                    checksum(table)
+                }
+            });
+
+            total = match (total, computed) {
+                (Some(total), Some(computed)) => Some(total.wrapping_add(computed)),
+                _ => None,
+            };
+
+            if computed != Some(record.check_sum) {
+                mismatches.push(ChecksumMismatch {
+                    tag: record.tag.to_string(),
+                    stored: record.check_sum,
+                    computed,
+                });
+            }
+        }
+
+        let expected_check_sum_adjustment = total.map(|total| CHECKSUM_MAGIC.wrapping_sub(total));
+
+        Self {
+            is_valid: mismatches.is_empty()
+                && check_sum_adjustment.is_some()
+                && check_sum_adjustment == expected_check_sum_adjustment,
+            mismatches,
+            check_sum_adjustment,
+            expected_check_sum_adjustment,
+        }
+    }
+}
+
+/// Calculates a [table checksum](https://docs.microsoft.com/en-us/typography/opentype/spec/otff#calculating-checksums).
+///
+/// Data is padded with zeros to a multiple of 4 bytes.
+fn checksum(data: &[u8]) -> u32 {
+    data.chunks(4).fold(0u32, |sum, chunk| {
+        let mut word = [0; 4];
+        word[..chunk.len()].copy_from_slice(chunk);
+        sum.wrapping_add(u32::from_be_bytes(word))
+    })
+}
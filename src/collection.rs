@@ -0,0 +1,64 @@
+use serde::Serialize;
+#[cfg(feature = "wasm")]
+use tsify::Tsify;
+use ttf_parser::{Face, Tag};
+
+use crate::directory::find_table;
+use crate::FontError;
+
+/// A short description of a face in a font collection.
+#[derive(Clone, Debug, Serialize)]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi))]
+pub struct FaceSummary {
+    /// A face index inside the collection.
+    pub index: u32,
+
+    /// A face family name, see [`Font::family_name`](crate::Font::family_name).
+    pub family: Option<String>,
+
+    /// A face subfamily name, see [`Font::subfamily_name`](crate::Font::subfamily_name).
+    pub subfamily: Option<String>,
+}
+
+impl FaceSummary {
+    /// Summarizes a parsed face at the specified collection index.
+    pub fn new(face: &Face, index: u32) -> Self {
+        let data = find_table(face.raw_face(), Tag::from_bytes(b"name")).unwrap_or_default();
+        let name = crate::tables::name::Table::new(face.tables().name, data);
+        let name = name.as_ref();
+
+        Self {
+            index,
+            family: name.and_then(|v| v.family_name()).map(str::to_string),
+            subfamily: name.and_then(|v| v.subfamily_name()).map(str::to_string),
+        }
+    }
+}
+
+/// Returns the number of faces in a font.
+///
+/// A regular font is treated as a collection with a single face.
+/// Returns `0` when the data is neither a font nor a font collection.
+pub fn faces_in_collection(data: &[u8]) -> u32 {
+    match ttf_parser::fonts_in_collection(data) {
+        Some(count) => count,
+        None if Face::parse(data, 0).is_ok() => 1,
+        None => 0,
+    }
+}
+
+/// Parses every face in a font.
+///
+/// Returns a result for each face, so a broken face doesn't hide the rest
+/// of the collection.
+pub fn parse_all(data: &[u8]) -> Vec<Result<FaceSummary, FontError>> {
+    // Report a broken font instead of an empty list.
+    let count = faces_in_collection(data).max(1);
+
+    (0..count)
+        .map(|index| match Face::parse(data, index) {
+            Ok(face) => Ok(FaceSummary::new(&face, index)),
+            Err(err) => Err(FontError::from_parsing_error(err, data, index)),
+        })
+        .collect()
+}
@@ -0,0 +1,116 @@
+use serde::Serialize;
+#[cfg(feature = "wasm")]
+use tsify::Tsify;
+use ttf_parser::{RawFace, Tag};
+
+/// An sfnt flavor, derived from the sfnt version.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi))]
+pub enum Flavor {
+    /// TrueType outlines, sfnt version `0x00010000`.
+    TrueType,
+    /// CFF outlines, sfnt version `OTTO`.
+    Cff,
+    /// Apple TrueType, sfnt version `true`.
+    AppleTrueType,
+}
+
+impl Flavor {
+    fn from_sfnt_version(sfnt_version: u32) -> Option<Flavor> {
+        match &sfnt_version.to_be_bytes() {
+            [0x00, 0x01, 0x00, 0x00] => Some(Flavor::TrueType),
+            b"OTTO" => Some(Flavor::Cff),
+            b"true" => Some(Flavor::AppleTrueType),
+            _ => None,
+        }
+    }
+}
+
+/// A [Table Record](https://docs.microsoft.com/en-us/typography/opentype/spec/otff#table-directory).
+#[derive(Clone, Debug, Serialize)]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi))]
+pub struct TableRecord {
+    /// Table identifier.
+    pub tag: String,
+
+    /// Offset from the beginning of the font data.
+    pub offset: u32,
+
+    /// Length of the table in bytes.
+    pub length: u32,
+
+    /// Checksum stored in the table record.
+    pub checksum: u32,
+}
+
+/// A [Table Directory](https://docs.microsoft.com/en-us/typography/opentype/spec/otff#table-directory).
+#[derive(Clone, Debug, Serialize)]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi))]
+#[serde(rename_all = "camelCase")]
+pub struct TableDirectory {
+    /// The raw sfnt version.
+    pub sfnt_version: u32,
+
+    /// The sfnt flavor.
+    pub flavor: Option<Flavor>,
+
+    /// Table records in the order they are stored in the font.
+    ///
+    /// The specification requires them to be sorted by tag, but not every font does so.
+    pub tables: Vec<TableRecord>,
+}
+
+impl TableDirectory {
+    /// Reads the table directory of a face.
+    ///
+    /// `index` is only used to find the sfnt version of a face in a font collection.
+    pub fn new(raw_face: &RawFace, index: u32) -> Self {
+        let sfnt_version = face_offset(raw_face.data, index)
+            .and_then(|offset| read_u32(raw_face.data, offset as usize))
+            .unwrap_or_default();
+
+        let tables = raw_face
+            .table_records
+            .into_iter()
+            .map(|record| TableRecord {
+                tag: record.tag.to_string(),
+                offset: record.offset,
+                length: record.length,
+                checksum: record.check_sum,
+            })
+            .collect();
+
+        Self {
+            sfnt_version,
+            flavor: Flavor::from_sfnt_version(sfnt_version),
+            tables,
+        }
+    }
+}
+
+/// Returns the raw data of a table.
+///
+/// Unlike `RawFace::table`, doesn't require table records to be sorted.
+pub(crate) fn find_table<'a>(raw_face: &RawFace<'a>, tag: Tag) -> Option<&'a [u8]> {
+    let record = raw_face
+        .table_records
+        .into_iter()
+        .find(|record| record.tag == tag)?;
+    let start = record.offset as usize;
+    let end = start.checked_add(record.length as usize)?;
+    raw_face.data.get(start..end)
+}
+
+/// Returns the offset of the face magic.
+pub(crate) fn face_offset(data: &[u8], index: u32) -> Option<u32> {
+    if data.get(0..4)? != b"ttcf" {
+        return Some(0);
+    }
+
+    read_u32(data, 12 + 4 * index as usize)
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes = data.get(offset..offset.checked_add(4)?)?;
+    Some(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
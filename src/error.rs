@@ -0,0 +1,188 @@
+use std::fmt;
+
+use serde::Serialize;
+#[cfg(feature = "wasm")]
+use tsify::Tsify;
+use ttf_parser::{FaceParsingError, FaceTables, RawFace, Tag};
+
+use crate::directory::face_offset;
+
+/// A kind of [`FontError`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi))]
+pub enum FontErrorKind {
+    /// Face data must start with `0x00010000`, `0x74727565`, `0x4F54544F` or `0x74746366`.
+    UnknownMagic,
+    /// An attempt to read out of bounds detected.
+    MalformedFont,
+    /// The face index is larger than the number of faces in the font.
+    FaceIndexOutOfBounds,
+    /// The `head` table is missing or malformed.
+    NoHeadTable,
+    /// The `hhea` table is missing or malformed.
+    NoHheaTable,
+    /// The `maxp` table is missing or malformed.
+    NoMaxpTable,
+    /// An optional table has invalid data and was skipped.
+    MalformedTable,
+}
+
+/// A font parsing error.
+///
+/// Returned when a face cannot be parsed and also used to report
+/// skipped optional tables as warnings.
+#[derive(Clone, Debug, Serialize)]
+pub struct FontError {
+    /// The kind of the error.
+    pub kind: FontErrorKind,
+
+    /// A tag of the table involved, if any.
+    pub table: Option<String>,
+
+    /// A byte offset into the font data, when known.
+    pub offset: Option<u32>,
+
+    message: String,
+}
+
+impl FontError {
+    /// A human readable error message.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Creates a `FontError` from a `ttf-parser` error.
+    ///
+    /// `data` and `index` are used to find the offset of the data involved.
+    pub fn from_parsing_error(err: FaceParsingError, data: &[u8], index: u32) -> Self {
+        Self::with_offsets(err, &ErrorOffsets::new(data, index))
+    }
+
+    /// Creates a `FontError` from a `ttf-parser` error using offsets located beforehand.
+    pub(crate) fn with_offsets(err: FaceParsingError, offsets: &ErrorOffsets) -> Self {
+        let (kind, tag, offset) = match err {
+            FaceParsingError::UnknownMagic => (FontErrorKind::UnknownMagic, None, offsets.face),
+            FaceParsingError::MalformedFont => (FontErrorKind::MalformedFont, None, None),
+            FaceParsingError::FaceIndexOutOfBounds => {
+                (FontErrorKind::FaceIndexOutOfBounds, None, None)
+            }
+            FaceParsingError::NoHeadTable => {
+                (FontErrorKind::NoHeadTable, Some(b"head"), offsets.head)
+            }
+            FaceParsingError::NoHheaTable => {
+                (FontErrorKind::NoHheaTable, Some(b"hhea"), offsets.hhea)
+            }
+            FaceParsingError::NoMaxpTable => {
+                (FontErrorKind::NoMaxpTable, Some(b"maxp"), offsets.maxp)
+            }
+        };
+
+        Self {
+            kind,
+            table: tag.map(|tag| Tag::from_bytes(tag).to_string()),
+            offset,
+            message: err.to_string(),
+        }
+    }
+
+    fn malformed_table(tag: Tag, offset: u32, message: &str) -> Self {
+        Self {
+            kind: FontErrorKind::MalformedTable,
+            table: Some(tag.to_string()),
+            offset: Some(offset),
+            message: format!("the {} table {}", tag, message),
+        }
+    }
+}
+
+impl fmt::Display for FontError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for FontError {}
+
+/// Offsets used to report where face parsing has failed.
+///
+/// Only the table directory is read, so they are cheap to locate before
+/// the font data is handed over to `ttf-parser`.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct ErrorOffsets {
+    face: Option<u32>,
+    head: Option<u32>,
+    hhea: Option<u32>,
+    maxp: Option<u32>,
+}
+
+impl ErrorOffsets {
+    pub(crate) fn new(data: &[u8], index: u32) -> Self {
+        let raw_face = RawFace::parse(data, index).ok();
+        let find = |tag: &[u8; 4]| {
+            raw_face
+                .as_ref()?
+                .table_records
+                .into_iter()
+                .find(|record| record.tag == Tag::from_bytes(tag))
+                .map(|record| record.offset)
+        };
+
+        Self {
+            face: face_offset(data, index),
+            head: find(b"head"),
+            hhea: find(b"hhea"),
+            maxp: find(b"maxp"),
+        }
+    }
+}
+
+/// Checks that an optional table was parsed.
+///
+/// Returns `None` for tables that are not parsed by `ttf-parser`.
+fn is_table_parsed(tables: &FaceTables, tag: Tag) -> Option<bool> {
+    let parsed = match &tag.to_bytes() {
+        b"cmap" => tables.cmap.is_some(),
+        b"hmtx" => tables.hmtx.is_some(),
+        b"kern" => tables.kern.is_some(),
+        b"name" => tables.name.is_some(),
+        b"OS/2" => tables.os2.is_some(),
+        b"post" => tables.post.is_some(),
+        b"vhea" => tables.vhea.is_some(),
+        b"vmtx" => tables.vmtx.is_some(),
+        b"VORG" => tables.vorg.is_some(),
+        b"GDEF" => tables.gdef.is_some(),
+        b"GPOS" => tables.gpos.is_some(),
+        b"GSUB" => tables.gsub.is_some(),
+        b"fvar" => tables.fvar.is_some(),
+        _ => return None,
+    };
+
+    Some(parsed)
+}
+
+/// Collects optional tables that were skipped while parsing the face.
+pub fn collect_warnings(raw_face: &RawFace, tables: &FaceTables) -> Vec<FontError> {
+    raw_face
+        .table_records
+        .into_iter()
+        .filter_map(|record| {
+            let end = record.offset.checked_add(record.length);
+            if end.is_none_or(|end| end as usize > raw_face.data.len()) {
+                return Some(FontError::malformed_table(
+                    record.tag,
+                    record.offset,
+                    "points outside of the font data",
+                ));
+            }
+
+            match is_table_parsed(tables, record.tag) {
+                Some(false) => Some(FontError::malformed_table(
+                    record.tag,
+                    record.offset,
+                    "has invalid data and was skipped",
+                )),
+                _ => None,
+            }
+        })
+        .collect()
+}
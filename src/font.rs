@@ -0,0 +1,600 @@
+use std::cell::OnceCell;
+
+use owned_ttf_parser::{AsFaceRef, Face, GlyphId, OwnedFace, Tag};
+
+use crate::checksum::ChecksumReport;
+use crate::directory::{self, TableDirectory};
+use crate::error::{self, ErrorOffsets, FontError};
+use crate::tables::{
+    self,
+    name::NameId,
+    os2::{Permissions, Purpose, ScriptMetrics, Style, Width},
+    LineMetrics, Tables,
+};
+
+/// A font face.
+///
+/// Owns the font data. `ttf-parser` parses the face on construction, while
+/// the decoded models, like tables, names and checksums, are computed when
+/// first accessed.
+pub struct Font {
+    face: OwnedFace,
+    index: u32,
+
+    directory: OnceCell<TableDirectory>,
+    checksums: OnceCell<ChecksumReport>,
+
+    head: OnceCell<tables::head::Table>,
+    hhea: OnceCell<tables::hhea::Table>,
+    maxp: OnceCell<tables::maxp::Table>,
+    os2: OnceCell<Option<tables::os2::Table>>,
+    name: OnceCell<Option<tables::name::Table>>,
+    post: OnceCell<Option<tables::post::Table>>,
+    vhea: OnceCell<Option<tables::vhea::Table>>,
+    vmtx: OnceCell<Option<tables::vmtx::Table>>,
+    vorg: OnceCell<Option<tables::vorg::Table>>,
+    warnings: OnceCell<Vec<FontError>>,
+}
+
+impl Font {
+    /// Creates a new `Font` from a raw data.
+    ///
+    /// `index` indicates the specific font face in a font collection.
+    /// Use [`faces_in_collection`](crate::faces_in_collection) to get the total
+    /// number of font faces. Set to 0 if unsure.
+    ///
+    /// Required tables: `head`, `hhea` and `maxp`.
+    ///
+    /// If an optional table has invalid data it will be skipped and reported
+    /// in [`warnings`](Font::warnings).
+    pub fn new(data: Vec<u8>, index: u32) -> Result<Self, FontError> {
+        // `OwnedFace` takes the data by value, so we have to locate the tables
+        // it requires first to be able to report where parsing has failed.
+        let offsets = ErrorOffsets::new(&data, index);
+        let face = OwnedFace::from_vec(data, index)
+            .map_err(|err| FontError::with_offsets(err, &offsets))?;
+
+        Ok(Self {
+            face,
+            index,
+            directory: OnceCell::new(),
+            checksums: OnceCell::new(),
+            head: OnceCell::new(),
+            hhea: OnceCell::new(),
+            maxp: OnceCell::new(),
+            os2: OnceCell::new(),
+            name: OnceCell::new(),
+            post: OnceCell::new(),
+            vhea: OnceCell::new(),
+            vmtx: OnceCell::new(),
+            vorg: OnceCell::new(),
+            warnings: OnceCell::new(),
+        })
+    }
+
+    /// Returns the underlying `ttf-parser` face.
+    pub fn face(&self) -> &Face<'_> {
+        self.face.as_face_ref()
+    }
+
+    /// Returns the raw font data.
+    pub fn data(&self) -> &[u8] {
+        self.face.as_slice()
+    }
+
+    /// Returns the face index in a font collection.
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// Returns every table record of the face along with the sfnt version.
+    pub fn table_directory(&self) -> &TableDirectory {
+        self.directory
+            .get_or_init(|| TableDirectory::new(self.face().raw_face(), self.index))
+    }
+
+    /// Recalculates every table checksum and `head.checkSumAdjustment`.
+    ///
+    /// `ttf-parser` doesn't check them, so a corrupted font can still be parsed.
+    pub fn verify_checksums(&self) -> &ChecksumReport {
+        self.checksums
+            .get_or_init(|| ChecksumReport::new(self.face().raw_face(), self.index))
+    }
+
+    /// Returns the raw data of a table.
+    ///
+    /// Tags shorter than 4 characters are padded with spaces, so `CFF`
+    /// matches the `CFF ` table.
+    ///
+    /// Returns `None` when the table is not present or out of bounds.
+    pub fn raw_table(&self, tag: &str) -> Option<&[u8]> {
+        if tag.is_empty() || tag.len() > 4 {
+            return None;
+        }
+
+        directory::find_table(
+            self.face().raw_face(),
+            Tag::from_bytes_lossy(tag.as_bytes()),
+        )
+    }
+
+    /// Optional tables that have invalid data and were skipped.
+    pub fn warnings(&self) -> &[FontError] {
+        self.warnings.get_or_init(|| {
+            let face = self.face();
+            error::collect_warnings(face.raw_face(), face.tables())
+        })
+    }
+
+    /// Returns face's weight class.
+    ///
+    /// Returns `400` when OS/2 table is not present.
+    pub fn weight_class(&self) -> u16 {
+        self.face().weight().to_number()
+    }
+
+    /// Returns face's width class.
+    ///
+    /// Returns `5` when OS/2 table is not present.
+    pub fn width_class(&self) -> u16 {
+        self.os2().map_or(5, |os2| os2.width_class)
+    }
+
+    /// Returns face's width as a percentage of the normal width.
+    ///
+    /// Returns `100` when OS/2 table is not present.
+    pub fn width_percent(&self) -> f64 {
+        Width::from(self.face().width()).to_percent()
+    }
+
+    /// Returns CSS `font-weight` value.
+    pub fn css_font_weight(&self) -> String {
+        self.weight_class().clamp(1, 1000).to_string()
+    }
+
+    /// Returns CSS `font-stretch` keyword.
+    pub fn css_font_stretch(&self) -> &'static str {
+        Width::from(self.face().width()).to_css()
+    }
+
+    /// Checks that face is marked as *Bold*.
+    ///
+    /// Returns `false` when OS/2 table is not present.
+    pub fn is_bold(&self) -> bool {
+        self.face().is_bold()
+    }
+
+    /// Checks that face is marked as *Italic*.
+    ///
+    /// Returns `false` when OS/2 table is not present.
+    pub fn is_italic(&self) -> bool {
+        self.face().is_italic()
+    }
+
+    /// Checks that face is marked as *Monospaced*.
+    ///
+    /// Returns `false` when `post` table is not present.
+    pub fn is_monospaced(&self) -> bool {
+        self.face().is_monospaced()
+    }
+
+    /// Checks that face is marked as *Oblique*.
+    ///
+    /// Returns `false` when OS/2 table is not present or when its version is < 4.
+    pub fn is_oblique(&self) -> bool {
+        self.face().is_oblique()
+    }
+
+    /// Checks that face is marked as *Regular*.
+    ///
+    /// Returns `false` when OS/2 table is not present.
+    pub fn is_regular(&self) -> bool {
+        self.face().is_regular()
+    }
+
+    /// Checks that face is variable.
+    ///
+    /// Simply checks the presence of a `fvar` table.
+    pub fn is_variable(&self) -> bool {
+        self.face().is_variable()
+    }
+
+    // Read https://github.com/freetype/freetype/blob/49270c17011491227ec7bd3fb73ede4f674aa065/src/sfnt/sfobjs.c#L1279
+    // to learn more about the logic behind the following property.
+    /// Returns a horizontal face ascender.
+    ///
+    /// This property is affected by variation axes.
+    pub fn ascender(&self) -> i16 {
+        self.face().ascender()
+    }
+
+    /// Returns a horizontal face descender.
+    ///
+    /// This property is affected by variation axes.
+    pub fn descender(&self) -> i16 {
+        self.face().descender()
+    }
+
+    /// Returns face's height.
+    ///
+    /// This property is affected by variation axes.
+    pub fn height(&self) -> i16 {
+        self.face().height()
+    }
+
+    /// Returns a horizontal face line gap.
+    ///
+    /// This property is affected by variation axes.
+    pub fn line_gap(&self) -> i16 {
+        self.face().line_gap()
+    }
+
+    /// Returns a horizontal typographic face ascender.
+    ///
+    /// Prefer `ascender` unless you explicitly want this. This is a more
+    /// low-level alternative.
+    ///
+    /// This property is affected by variation axes.
+    ///
+    /// Returns `None` when OS/2 table is not present.
+    pub fn typographic_ascender(&self) -> Option<i16> {
+        self.face().typographic_ascender()
+    }
+
+    /// Returns a horizontal typographic face ascender.
+    ///
+    /// Prefer `ascender` unless you explicitly want this. This is a more
+    /// low-level alternative.
+    ///
+    /// This property is affected by variation axes.
+    ///
+    /// Returns `None` when OS/2 table is not present.
+    pub fn typographic_descender(&self) -> Option<i16> {
+        self.face().typographic_descender()
+    }
+
+    /// Returns a horizontal typographic face line gap.
+    ///
+    /// Prefer `line_gap` unless you explicitly want this. This is a more
+    /// low-level alternative.
+    ///
+    /// This property is affected by variation axes.
+    ///
+    /// Returns `None` when OS/2 table is not present.
+    pub fn typographic_line_gap(&self) -> Option<i16> {
+        self.face().typographic_line_gap()
+    }
+
+    /// Returns a vertical face ascender.
+    ///
+    /// This property is affected by variation axes.
+    pub fn vertical_ascender(&self) -> Option<i16> {
+        self.face().vertical_ascender()
+    }
+
+    /// Returns a vertical face descender.
+    ///
+    /// This property is affected by variation axes.
+    pub fn vertical_descender(&self) -> Option<i16> {
+        self.face().vertical_descender()
+    }
+
+    /// Returns a vertical face height.
+    ///
+    /// This method is affected by variation axes.
+    pub fn vertical_height(&self) -> Option<i16> {
+        self.face().vertical_height()
+    }
+
+    /// Returns a vertical face line gap.
+    ///
+    /// This property is affected by variation axes.
+    pub fn vertical_line_gap(&self) -> Option<i16> {
+        self.face().vertical_line_gap()
+    }
+
+    /// Returns face's units per EM.
+    ///
+    /// Guarantee to be in a 16..=16384 range.
+    pub fn units_per_em(&self) -> u16 {
+        self.face().units_per_em()
+    }
+
+    /// Returns face's x height.
+    ///
+    /// This property is affected by variation axes.
+    ///
+    /// Returns `None` when OS/2 table is not present or when its version is < 2.
+    pub fn x_height(&self) -> Option<i16> {
+        self.face().x_height()
+    }
+
+    /// Returns face's capital height.
+    ///
+    /// This property is affected by variation axes.
+    ///
+    /// Returns `None` when OS/2 table is not present or when its version is < 2.
+    pub fn capital_height(&self) -> Option<i16> {
+        self.face().capital_height()
+    }
+
+    /// Returns face's italic angle.
+    ///
+    /// Returns `None` when `post` table is not present.
+    pub fn italic_angle(&self) -> Option<f32> {
+        self.face().italic_angle()
+    }
+
+    /// Returns face permissions.
+    pub fn permissions(&self) -> Option<Permissions> {
+        self.os2().map(|v| v.permissions)
+    }
+
+    /// Checks that the face embedding permissions allow the specified purpose.
+    ///
+    /// Returns `false` when OS/2 table is not present.
+    pub fn can_use(&self, purpose: Purpose) -> bool {
+        self.os2().is_some_and(|os2| os2.fs_type.can_use(purpose))
+    }
+
+    /// Checks if the face subsetting is allowed.
+    pub fn is_subsetting_allowed(&self) -> bool {
+        self.face().is_subsetting_allowed()
+    }
+
+    /// Checks if the face bitmaps embedding is allowed.
+    pub fn is_bitmap_embedding_allowed(&self) -> bool {
+        self.face().is_bitmap_embedding_allowed()
+    }
+
+    /// Returns a total number of glyphs in the face.
+    ///
+    /// Never zero.
+    pub fn number_of_glyphs(&self) -> u16 {
+        self.face().number_of_glyphs()
+    }
+
+    /// Returns face's superscript metrics.
+    ///
+    /// This property is affected by variation axes.
+    ///
+    /// Returns `None` when OS/2 table is not present.
+    pub fn superscript_metrics(&self) -> Option<ScriptMetrics> {
+        self.face().superscript_metrics().map(ScriptMetrics::from)
+    }
+
+    /// Returns glyph's vertical advance.
+    ///
+    /// This method is affected by variation axes.
+    ///
+    /// Returns `None` when `vmtx` table is not present or the glyph is out of bounds.
+    pub fn glyph_ver_advance(&self, glyph_id: u16) -> Option<u16> {
+        self.face().glyph_ver_advance(GlyphId(glyph_id))
+    }
+
+    /// Returns glyph's vertical side bearing.
+    ///
+    /// This method is affected by variation axes.
+    ///
+    /// Returns `None` when `vmtx` table is not present or the glyph is out of bounds.
+    pub fn glyph_ver_side_bearing(&self, glyph_id: u16) -> Option<i16> {
+        self.face().glyph_ver_side_bearing(GlyphId(glyph_id))
+    }
+
+    /// Returns glyph's vertical origin according to the `VORG` table.
+    ///
+    /// Returns `None` when `VORG` table is not present.
+    pub fn glyph_y_origin(&self, glyph_id: u16) -> Option<i16> {
+        self.face().glyph_y_origin(GlyphId(glyph_id))
+    }
+
+    /// Returns face's subscript metrics.
+    ///
+    /// This property is affected by variation axes.
+    ///
+    /// Returns `None` when OS/2 table is not present.
+    pub fn subscript_metrics(&self) -> Option<ScriptMetrics> {
+        self.face().subscript_metrics().map(ScriptMetrics::from)
+    }
+
+    /// Returns face's underline metrics.
+    ///
+    /// This property is affected by variation axes.
+    ///
+    /// Returns `None` when `post` table is not present.
+    pub fn underline_metrics(&self) -> Option<LineMetrics> {
+        self.face().underline_metrics().map(LineMetrics::from)
+    }
+
+    /// Returns face's strikeout metrics.
+    ///
+    /// This property is affected by variation axes.
+    ///
+    /// Returns `None` when OS/2 table is not present.
+    pub fn strikeout_metrics(&self) -> Option<LineMetrics> {
+        self.face().strikeout_metrics().map(LineMetrics::from)
+    }
+
+    /// Returns face style.
+    ///
+    /// Returns `Normal` when OS/2 table is not present.
+    pub fn style(&self) -> Style {
+        self.face().style().into()
+    }
+
+    /// Returns a localized name.
+    ///
+    /// Locales are BCP 47 tags in the order of preference. See
+    /// [`get_name`](tables::name::Table::get_name) for how they are matched.
+    ///
+    /// Returns `None` when `name` table is not present or has no such name.
+    pub fn get_name(&self, name_id: NameId, preferred_locales: &[&str]) -> Option<String> {
+        self.name()?
+            .get_name(name_id, preferred_locales)
+            .map(|record| record.name.clone())
+    }
+
+    /// Returns a name by a numeric name ID, preferring English and Windows names.
+    ///
+    /// Unlike [`get_name`](Self::get_name), works with font-specific IDs from 256
+    /// and up, e.g. the ones referenced by `fvar` axes or stylistic sets.
+    pub fn name_by_id(&self, id: u16) -> Option<String> {
+        self.name()?.name_by_id(id).map(str::to_string)
+    }
+
+    /// Returns a family name.
+    ///
+    /// Prefers the typographic family over the legacy one and Windows names over
+    /// Macintosh ones. Returns `None` when `name` table is not present.
+    pub fn family_name(&self) -> Option<String> {
+        self.name()?.family_name().map(str::to_string)
+    }
+
+    /// Returns a subfamily name.
+    ///
+    /// Prefers the typographic subfamily over the legacy one.
+    pub fn subfamily_name(&self) -> Option<String> {
+        self.name()?.subfamily_name().map(str::to_string)
+    }
+
+    /// Returns a full name.
+    pub fn full_name(&self) -> Option<String> {
+        self.name()?.full_name().map(str::to_string)
+    }
+
+    /// Returns a PostScript name.
+    pub fn post_script_name(&self) -> Option<String> {
+        self.name()?.post_script_name().map(str::to_string)
+    }
+
+    /// Returns a typographic family name.
+    pub fn typographic_family(&self) -> Option<String> {
+        self.name()?.typographic_family().map(str::to_string)
+    }
+
+    /// Returns a WWS family name.
+    pub fn wws_family(&self) -> Option<String> {
+        self.name()?.wws_family().map(str::to_string)
+    }
+
+    /// Returns a bounding box that large enough to enclose any glyph from the face.
+    pub fn global_bounding_box(&self) -> tables::Rect {
+        self.head().global_bbox
+    }
+
+    /// A [Font Header Table](https://docs.microsoft.com/en-us/typography/opentype/spec/head).
+    pub fn head(&self) -> &tables::head::Table {
+        self.head.get_or_init(|| {
+            let data = self.raw_table("head").unwrap_or_default();
+            tables::head::Table::new(self.face().tables().head, data)
+                .expect("head table was already checked by ttf-parser")
+        })
+    }
+
+    /// A [Horizontal Header Table](https://docs.microsoft.com/en-us/typography/opentype/spec/hhea).
+    pub fn hhea(&self) -> &tables::hhea::Table {
+        self.hhea.get_or_init(|| {
+            let data = self.raw_table("hhea").unwrap_or_default();
+            tables::hhea::Table::new(self.face().tables().hhea, data)
+                .expect("hhea table was already checked by ttf-parser")
+        })
+    }
+
+    /// A [Maximum Profile Table](https://docs.microsoft.com/en-us/typography/opentype/spec/maxp).
+    pub fn maxp(&self) -> &tables::maxp::Table {
+        self.maxp.get_or_init(|| {
+            let data = self.raw_table("maxp").unwrap_or_default();
+            tables::maxp::Table::new(self.face().tables().maxp, data)
+                .expect("maxp table was already checked by ttf-parser")
+        })
+    }
+
+    /// A [OS/2 and Windows Metrics Table](https://docs.microsoft.com/en-us/typography/opentype/spec/os2).
+    pub fn os2(&self) -> Option<&tables::os2::Table> {
+        self.os2
+            .get_or_init(|| {
+                let data = self.raw_table("OS/2").unwrap_or_default();
+                tables::os2::Table::new(self.face().tables().os2, data)
+            })
+            .as_ref()
+    }
+
+    /// A [Naming Table](https://docs.microsoft.com/en-us/typography/opentype/spec/name).
+    pub fn name(&self) -> Option<&tables::name::Table> {
+        self.name
+            .get_or_init(|| {
+                let data = self.raw_table("name").unwrap_or_default();
+                tables::name::Table::new(self.face().tables().name, data)
+            })
+            .as_ref()
+    }
+
+    /// A [PostScript Table](https://docs.microsoft.com/en-us/typography/opentype/spec/post).
+    pub fn post(&self) -> Option<&tables::post::Table> {
+        self.post
+            .get_or_init(|| {
+                let data = self.raw_table("post").unwrap_or_default();
+                tables::post::Table::new(self.face().tables().post, data, self.number_of_glyphs())
+            })
+            .as_ref()
+    }
+
+    /// A [Vertical Header Table](https://docs.microsoft.com/en-us/typography/opentype/spec/vhea).
+    pub fn vhea(&self) -> Option<&tables::vhea::Table> {
+        self.vhea
+            .get_or_init(|| {
+                let data = self.raw_table("vhea").unwrap_or_default();
+                tables::vhea::Table::new(self.face().tables().vhea, data)
+            })
+            .as_ref()
+    }
+
+    /// A [Vertical Metrics Table](https://docs.microsoft.com/en-us/typography/opentype/spec/vmtx).
+    pub fn vmtx(&self) -> Option<&tables::vmtx::Table> {
+        self.vmtx
+            .get_or_init(|| {
+                tables::vmtx::Table::new(self.face().tables().vmtx, self.number_of_glyphs())
+            })
+            .as_ref()
+    }
+
+    /// A [Vertical Origin Table](https://docs.microsoft.com/en-us/typography/opentype/spec/vorg).
+    pub fn vorg(&self) -> Option<&tables::vorg::Table> {
+        self.vorg
+            .get_or_init(|| tables::vorg::Table::new(self.face().tables().vorg))
+            .as_ref()
+    }
+
+    /// All parsed tables.
+    ///
+    /// Parses every table that wasn't accessed yet.
+    pub fn tables(&self) -> Tables {
+        Tables {
+            head: *self.head(),
+            hhea: *self.hhea(),
+            maxp: *self.maxp(),
+            os2: self.os2().cloned(),
+            name: self.name().cloned(),
+            post: self.post().cloned(),
+            vhea: self.vhea().copied(),
+            vmtx: self.vmtx().cloned(),
+            vorg: self.vorg().cloned(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::abbvoice;
+
+    #[test]
+    fn tables_are_parsed_on_demand() {
+        let font = abbvoice();
+        assert!(font.head.get().is_none());
+
+        assert_eq!(font.head().units_per_em, font.units_per_em());
+        assert!(font.head.get().is_some());
+        assert!(font.os2.get().is_none());
+        assert!(font.warnings().is_empty());
+    }
+}
@@ -1,293 +1,518 @@
-use tables::os2::{Permissions, ScriptMetrics};
-use ttf_parser::Face;
-use wasm_bindgen::prelude::*;
-
+//! A font parser built on top of [`ttf-parser`](https://github.com/RazrFalcon/ttf-parser).
+//!
+//! [`Font`] and the table models can be used natively. The JavaScript bindings
+//! are available behind the `wasm` feature, which is enabled by default.
+
+mod checksum;
+mod collection;
+mod directory;
+mod error;
+mod font;
+mod parser;
 mod tables;
+#[cfg(feature = "wasm")]
+mod wasm;
+
+pub use checksum::{ChecksumMismatch, ChecksumReport};
+pub use collection::{faces_in_collection, parse_all, FaceSummary};
+pub use directory::{Flavor, TableDirectory, TableRecord};
+pub use error::{FontError, FontErrorKind};
+pub use font::Font;
+pub use tables::*;
+#[cfg(feature = "wasm")]
+pub use wasm::TTFParser;
 
-pub use tables::{Tables, TablesEnum};
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::num::NonZeroU16;
 
-// When the `wee_alloc` feature is enabled, use `wee_alloc` as the global
-// allocator.
-#[cfg(feature = "wee_alloc")]
-#[global_allocator]
-static ALLOC: wee_alloc::WeeAlloc = wee_alloc::WeeAlloc::INIT;
+    use super::{Flavor, Font, FontErrorKind};
 
-#[wasm_bindgen]
-extern "C" {
-    #[wasm_bindgen(js_namespace = console)]
-    fn log(s: &str);
-}
+    pub(crate) fn abbvoice() -> Font {
+        let bytes = fs::read("ABBvoice-Medium.ttf").unwrap();
+        Font::new(bytes, 0).unwrap()
+    }
 
-#[wasm_bindgen(inspectable)]
-pub struct TTFParser {
-    tables: Tables,
-
-    /// Checks that face is marked as *Bold*.
-    ///
-    /// Returns `false` when OS/2 table is not present.
-    #[wasm_bindgen(js_name = "isBold", readonly)]
-    pub is_bold: bool,
-
-    /// Checks that face is marked as *Italic*.
-    ///
-    /// Returns `false` when OS/2 table is not present.
-    #[wasm_bindgen(js_name = "isItalic", readonly)]
-    pub is_italic: bool,
-
-    /// Checks that face is marked as *Monospaced*.
-    ///
-    /// Returns `false` when `post` table is not present.
-    #[wasm_bindgen(js_name = "isMonospaced", readonly)]
-    pub is_monospaced: bool,
-
-    /// Checks that face is marked as *Oblique*.
-    ///
-    /// Returns `false` when OS/2 table is not present or when its version is < 4.
-    #[wasm_bindgen(js_name = "isOblique", readonly)]
-    pub is_oblique: bool,
-
-    /// Checks that face is marked as *Regular*.
-    ///
-    /// Returns `false` when OS/2 table is not present.
-    #[wasm_bindgen(js_name = "isRegular", readonly)]
-    pub is_regular: bool,
-
-    /// Checks that face is variable.
-    ///
-    /// Simply checks the presence of a `fvar` table.
-    #[wasm_bindgen(js_name = "isVariable", readonly)]
-    pub is_variable: bool,
-
-    // Read https://github.com/freetype/freetype/blob/49270c17011491227ec7bd3fb73ede4f674aa065/src/sfnt/sfobjs.c#L1279
-    // to learn more about the logic behind the following property.
-    /// Returns a horizontal face ascender.
-    ///
-    /// This property is affected by variation axes.
-    #[wasm_bindgen(js_name = "ascender", readonly)]
-    pub ascender: i16,
-
-    /// Returns a horizontal face descender.
-    ///
-    /// This property is affected by variation axes.
-    #[wasm_bindgen(js_name = "descender", readonly)]
-    pub descender: i16,
-
-    /// Returns face's height.
-    ///
-    /// This property is affected by variation axes.
-    #[wasm_bindgen(js_name = "height", readonly)]
-    pub height: i16,
-
-    /// Returns a horizontal face line gap.
-    ///
-    /// This property is affected by variation axes.
-    #[wasm_bindgen(js_name = "lineGap", readonly)]
-    pub line_gap: i16,
-
-    /// Returns a horizontal typographic face ascender.
-    ///
-    /// Prefer `ascender` unless you explicitly want this. This is a more
-    /// low-level alternative.
-    ///
-    /// This property is affected by variation axes.
-    ///
-    /// Returns `None` when OS/2 table is not present.
-    #[wasm_bindgen(js_name = "typographicAscender", readonly)]
-    pub typographic_ascender: Option<i16>,
-
-    /// Returns a horizontal typographic face ascender.
-    ///
-    /// Prefer `ascender` unless you explicitly want this. This is a more
-    /// low-level alternative.
-    ///
-    /// This property is affected by variation axes.
-    ///
-    /// Returns `None` when OS/2 table is not present.
-    #[wasm_bindgen(js_name = "typographicDescender", readonly)]
-    pub typographic_descender: Option<i16>,
-
-    /// Returns a horizontal typographic face line gap.
-    ///
-    /// Prefer `line_gap` unless you explicitly want this. This is a more
-    /// low-level alternative.
-    ///
-    /// This property is affected by variation axes.
-    ///
-    /// Returns `None` when OS/2 table is not present.
-    #[wasm_bindgen(js_name = "typographicLineGap", readonly)]
-    pub typographic_line_gap: Option<i16>,
-
-    /// Returns a vertical face ascender.
-    ///
-    /// This property is affected by variation axes.
-    #[wasm_bindgen(js_name = "verticalAscender", readonly)]
-    pub vertical_ascender: Option<i16>,
-
-    /// Returns a vertical face descender.
-    ///
-    /// This property is affected by variation axes.
-    #[wasm_bindgen(js_name = "verticalDescender", readonly)]
-    pub vertical_descender: Option<i16>,
-
-    /// Returns a vertical face height.
-    ///
-    /// This method is affected by variation axes.
-    #[wasm_bindgen(js_name = "verticalHeight", readonly)]
-    pub vertical_height: Option<i16>,
-
-    /// Returns a vertical face line gap.
-    ///
-    /// This property is affected by variation axes.
-    #[wasm_bindgen(js_name = "verticalLineGap", readonly)]
-    pub vertical_line_gap: Option<i16>,
-
-    /// Returns face's units per EM.
-    ///
-    /// Guarantee to be in a 16..=16384 range.
-    #[wasm_bindgen(js_name = "unitsPerEm", readonly)]
-    pub units_per_em: u16,
-
-    /// Returns face's x height.
-    ///
-    /// This property is affected by variation axes.
-    ///
-    /// Returns `undefined` when OS/2 table is not present or when its version is < 2.
-    #[wasm_bindgen(js_name = "xHeight", readonly)]
-    pub x_height: Option<i16>,
-
-    /// Returns face's capital height.
-    ///
-    /// This property is affected by variation axes.
-    ///
-    /// Returns `undefined` when OS/2 table is not present or when its version is < 2.
-    #[wasm_bindgen(js_name = "capitalHeight", readonly)]
-    pub capital_height: Option<i16>,
-
-    /// Returns face's italic angle.
-    ///
-    /// Returns `undefined` when `post` table is not present.
-    #[wasm_bindgen(js_name = "italicAngle", readonly)]
-    pub italic_angle: Option<f32>,
-
-    /// Returns face permissions.
-    pub permissions: Option<Permissions>,
-
-    /// Checks if the face subsetting is allowed.
-    #[wasm_bindgen(js_name = "isSubsettingAllowed", readonly)]
-    pub is_subsetting_allowed: bool,
-
-    /// Checks if the face bitmaps embedding is allowed.
-    #[wasm_bindgen(js_name = "isBitmapEmbeddingAllowed", readonly)]
-    pub is_bitmap_embedding_allowed: bool,
-
-    /// Returns a total number of glyphs in the face.
-    ///
-    /// Never zero.
-    #[wasm_bindgen(js_name = "numberOfGlyphs", readonly)]
-    pub number_of_glyphs: u16,
-
-    /// Returns face's superscript metrics.
-    ///
-    /// This property is affected by variation axes.
-    ///
-    /// Returns `undefined` when OS/2 table is not present.
-    #[wasm_bindgen(js_name = "superScriptMetrics", readonly)]
-    pub superscript_metrics: Option<ScriptMetrics>,
-    /* #[wasm_bindgen(js_name = "glyph_hor_advance")]
-    pub glyph_hor_advance: Option<u16>,
-
-    #[wasm_bindgen(js_name = "glyph_ver_advance")]
-    pub glyph_ver_advance: Option<u16>, */
-    // / Checks that face has non-default variation coordinates.
-    // #[cfg(feature = "variable-fonts")]
-    // #[wasm_bindgen(js_name = "hasNonDefaultVariationCoordinates")]
-    // pub has_non_default_variation_coordinates: bool,
-}
+    #[test]
+    fn reports_parsing_errors() {
+        let err = Font::new(b"true\0\0".to_vec(), 0).err().unwrap();
+        assert_eq!(err.kind, FontErrorKind::MalformedFont);
+
+        let err = Font::new(b"wOFF".to_vec(), 0).err().unwrap();
+        assert_eq!(err.kind, FontErrorKind::UnknownMagic);
+        assert_eq!(err.offset, Some(0));
+
+        let bytes = fs::read("ABBvoice-Medium.ttf").unwrap();
+        let err = Font::new(bytes, 1).err().unwrap();
+        assert_eq!(err.kind, FontErrorKind::FaceIndexOutOfBounds);
+        assert_eq!(err.table, None);
+    }
 
-#[wasm_bindgen]
-impl TTFParser {
-    /// Creates a new `TTFParser` from a raw data.
-    ///
-    /// `index` indicates the specific font face in a font collection.
-    /// Use [`fonts_in_collection`] to get the total number of font faces.
-    /// Defaults to 0 if not set.
-    ///
-    /// Required tables: `head`, `hhea` and `maxp`.
-    ///
-    /// If an optional table has invalid data it will be skipped.
-    #[wasm_bindgen(constructor)]
-    pub fn new(data: &[u8], index: Option<usize>) -> Result<TTFParser, JsError> {
-        let face = match Face::parse(data, index.unwrap_or_default() as u32) {
-            Ok(font) => font,
-            Err(err) => return Err(JsError::new(&err.to_string())),
-        };
+    #[test]
+    fn decodes_head_table() {
+        let font = abbvoice();
+        let head = font.head();
+        assert_eq!(head.major_version, 1);
+        assert!(head.font_revision > 0.0);
+        // Both dates are after 2000-01-01.
+        assert!(head.created.unwrap() > 946_684_800);
+        assert!(head.modified >= head.created);
+        assert_eq!(head.mac_style.bold, font.is_bold());
+    }
+
+    #[test]
+    fn rejects_out_of_range_head_dates() {
+        let font = abbvoice();
+        let mut data = font.raw_table("head").unwrap().to_vec();
+        data[20] = 0x80;
+        data[28..36].copy_from_slice(&i64::MIN.to_be_bytes());
+
+        let table = ttf_parser::head::Table::parse(&data).unwrap();
+        let head = crate::head::Table::new(table, &data).unwrap();
+        assert_eq!(head.created, None);
+        assert_eq!(head.modified, None);
+    }
+
+    #[test]
+    fn decodes_hhea_table() {
+        let font = abbvoice();
+        let hhea = font.hhea();
+        assert_eq!(hhea.ascender, font.face().tables().hhea.ascender);
+        assert!(hhea.advance_width_max > 0);
+        assert!(hhea.x_max_extent > 0);
+        assert_eq!(hhea.metric_data_format, 0);
+    }
+
+    #[test]
+    fn decodes_maxp_profile() {
+        let font = abbvoice();
+        let maxp = font.maxp();
+        assert_eq!(maxp.version, 1.0);
+        assert!(maxp.max_points.is_some());
+        assert!(maxp.max_component_depth.is_some());
+
+        let data = [0x00, 0x00, 0x50, 0x00, 0x00, 0x10];
+        let table = ttf_parser::maxp::Table::parse(&data).unwrap();
+        let maxp = crate::maxp::Table::new(table, &data).unwrap();
+        assert_eq!(maxp.version, 0.5);
+        assert_eq!(maxp.number_of_glyphs, 16);
+        assert!(maxp.max_points.is_none());
+    }
+
+    #[test]
+    fn decodes_post_glyph_names() {
+        let font = abbvoice();
+        let post = font.post().unwrap();
+        assert_eq!(post.version, 2.0);
+        assert_eq!(post.is_fixed_pitch, font.is_monospaced());
+        assert_eq!(post.glyph_names.len(), usize::from(font.number_of_glyphs()));
+        assert_eq!(post.glyph_names[0], ".notdef");
+        assert_eq!(
+            post.glyph_names.iter().position(|name| name == "A"),
+            font.face().glyph_index('A').map(|id| usize::from(id.0))
+        );
+    }
+
+    #[test]
+    fn decodes_vertical_metrics() {
+        let font = abbvoice();
+        assert!(font.vhea().is_none());
+        assert!(font.vmtx().is_none());
+        assert_eq!(font.glyph_ver_advance(0), None);
+
+        let mut data = vec![0x00, 0x01, 0x10, 0x00, 0x03, 0x70, 0xFC, 0x90, 0x00, 0x00];
+        data.extend_from_slice(&[0x03, 0xE8, 0x00, 0x32, 0xFF, 0xCE, 0x03, 0xB6]);
+        data.extend_from_slice(&[0; 16]);
+        data.extend_from_slice(&[0x00, 0x02]);
+        let table = ttf_parser::vhea::Table::parse(&data).unwrap();
+        let vhea = crate::vhea::Table::new(Some(table), &data).unwrap();
+        assert_eq!(vhea.version, 1.1);
+        assert_eq!(vhea.ascender, 880);
+        assert_eq!(vhea.advance_height_max, 1000);
+        assert_eq!(vhea.min_bottom_side_bearing, -50);
+        assert_eq!(vhea.number_of_metrics, 2);
+
+        let data = [0x03, 0xE8, 0x00, 0x78, 0x01, 0xF4, 0x00, 0x0A, 0x00, 0x14];
+        let table = ttf_parser::hmtx::Table::parse(2, NonZeroU16::new(3).unwrap(), &data).unwrap();
+        let vmtx = crate::vmtx::Table::new(Some(table), 3).unwrap();
+        assert_eq!(vmtx.metrics.len(), 3);
+        assert_eq!(vmtx.metrics[0].advance, 1000);
+        assert_eq!(vmtx.metrics[0].top_side_bearing, 120);
+        assert_eq!(vmtx.metrics[2].advance, 500);
+        assert_eq!(vmtx.metrics[2].top_side_bearing, 20);
+
+        let data = [
+            0x00, 0x01, 0x00, 0x00, 0x03, 0x70, 0x00, 0x01, 0x00, 0x05, 0x03, 0x20,
+        ];
+        let table = ttf_parser::vorg::Table::parse(&data).unwrap();
+        let vorg = crate::vorg::Table::new(Some(table)).unwrap();
+        assert_eq!(vorg.default_vert_origin_y, 880);
+        assert_eq!(vorg.vert_origin_y_metrics[0].glyph_index, 5);
+        assert_eq!(vorg.vert_origin_y_metrics[0].vert_origin_y, 800);
+    }
+
+    #[test]
+    fn decodes_unicode_ranges() {
+        let font = abbvoice();
+        let ranges = &font.os2().unwrap().unicode_ranges;
+        assert!(ranges.contains(0));
+        assert!(!ranges.contains(59));
+        assert!(!ranges.contains(200));
+        assert_eq!(ranges.names[0], "Basic Latin");
+        assert!(ranges
+            .names
+            .iter()
+            .any(|name| name == "Cyrillic Supplement"));
+        assert!(!ranges
+            .names
+            .iter()
+            .any(|name| name == "CJK Unified Ideographs"));
+    }
+
+    #[test]
+    fn decodes_code_page_ranges() {
+        let font = abbvoice();
+        let ranges = font.os2().unwrap().code_page_ranges.as_ref().unwrap();
+        assert!(ranges.contains(0));
+        assert!(!ranges.contains(17));
+        assert_eq!(ranges.names[0], "Latin 1");
+        assert!(ranges.names.iter().any(|name| name == "Cyrillic"));
+        assert_eq!(ranges.code_pages[..2], [1252, 1250]);
+    }
+
+    #[test]
+    fn decodes_panose() {
+        let font = abbvoice();
+        let panose = &font.os2().unwrap().panose;
+        assert_eq!(panose.bytes, [2, 13, 7, 3, 2, 5, 3, 2, 2, 4]);
+        assert_eq!(panose.family_kind, crate::panose::FamilyKind::LatinText);
+        assert_eq!(panose.digits.len(), 9);
+        assert_eq!(panose.digits[0].name.as_deref(), Some("Serif Style"));
+        assert_eq!(
+            panose.digits[0].meaning.as_deref(),
+            Some("Perpendicular Sans")
+        );
+        assert_eq!(panose.digits[1].meaning.as_deref(), Some("Demi"));
+
+        let panose = crate::panose::Panose::from([3, 2, 0, 1, 99, 0, 0, 0, 0, 0]);
+        assert_eq!(panose.digits[0].meaning.as_deref(), Some("Flat Nib"));
+        assert_eq!(panose.digits[1].meaning.as_deref(), Some("Any"));
+        assert_eq!(panose.digits[2].meaning.as_deref(), Some("No Fit"));
+        assert_eq!(panose.digits[3].meaning, None);
+    }
 
-        let tables = Tables::new(&face);
-        // log(format!("{:?}", data).as_str());
-
-        let permissions = tables.os2.map(|v| v.permissions);
-
-        let parser = Self {
-            tables,
-
-            is_bold: face.is_bold(),
-            is_italic: face.is_italic(),
-            is_monospaced: face.is_monospaced(),
-            is_oblique: face.is_oblique(),
-            is_regular: face.is_regular(),
-            is_subsetting_allowed: face.is_subsetting_allowed(),
-            is_variable: face.is_variable(),
-            // has_non_default_variation_coordinates: face.has_non_default_variation_coordinates(),
-            units_per_em: face.units_per_em(),
-            italic_angle: face.italic_angle(),
-
-            ascender: face.ascender(),
-            descender: face.descender(),
-            height: face.height(),
-            line_gap: face.line_gap(),
-            capital_height: face.capital_height(),
-            typographic_ascender: face.typographic_ascender(),
-            typographic_descender: face.typographic_descender(),
-            typographic_line_gap: face.typographic_line_gap(),
-            vertical_ascender: face.vertical_ascender(),
-            vertical_descender: face.vertical_descender(),
-            vertical_height: face.vertical_height(),
-            vertical_line_gap: face.vertical_line_gap(),
-            x_height: face.x_height(),
-            permissions,
-            is_bitmap_embedding_allowed: face.is_bitmap_embedding_allowed(),
-            number_of_glyphs: face.number_of_glyphs(),
-            superscript_metrics: face.superscript_metrics().map(ScriptMetrics::from),
-            // glyph_ver_advance: face.glyph_ver_advance(),
-            // glyph_hor_advance: face.glyph_hor_advance(),
+    #[test]
+    fn finds_registered_vendor() {
+        let font = abbvoice();
+        let os2 = font.os2().unwrap();
+        assert_eq!(os2.vendor_id, "DAMA");
+        assert_eq!(os2.vendor.as_ref().unwrap().name, "Dalton Maag Limited");
+
+        let vendor = crate::vendor::Vendor::find("MS").unwrap();
+        assert_eq!(vendor.id, "MS");
+        assert_eq!(vendor.name, "Microsoft Corp.");
+        assert!(crate::vendor::Vendor::find("GOOG").is_some());
+        assert!(crate::vendor::Vendor::find("goog").is_none());
+        assert!(crate::vendor::Vendor::find("UKWN").is_none());
+        assert_eq!(crate::vendor::Vendor::find("MS\0\0").unwrap().id, "MS");
+        for id in [
+            "3ip", "B&H", "FDI", "KLTF", "P22", "PfEd", "RSMS", "Sean", "robo", "zeta",
+        ] {
+            assert_eq!(crate::vendor::Vendor::find(id).unwrap().id, id);
+        }
+    }
+
+    #[test]
+    fn decodes_os2_fields() {
+        let font = abbvoice();
+        let os2 = font.os2().unwrap();
+        assert_eq!(os2.version, 4);
+        assert!(os2.x_avg_char_width > 0);
+        assert_eq!(os2.fs_selection.bits, 0xC0);
+        assert!(os2.fs_selection.regular);
+        assert!(os2.fs_selection.use_typo_metrics);
+        assert!(!os2.fs_selection.italic);
+        assert_eq!(
+            os2.fs_selection.use_typo_metrics,
+            os2.use_typographic_metrics
+        );
+        assert!(os2.first_char_index <= os2.last_char_index);
+        assert!(os2.max_context.is_some());
+        assert!(os2.optical_point_size_range.is_none());
+    }
+
+    #[test]
+    fn preserves_weight_and_width() {
+        let font = abbvoice();
+        let os2 = font.os2().unwrap();
+        assert_eq!(os2.weight_class, 500);
+        assert_eq!(os2.css_font_weight, "500");
+        assert_eq!(os2.width_class, 5);
+        assert_eq!(os2.width_percent, 100.0);
+        assert_eq!(os2.css_font_stretch, "normal");
+        assert_eq!(font.weight_class(), 500);
+        assert_eq!(font.css_font_stretch(), "normal");
+
+        let weight = crate::Weight::from(ttf_parser::Weight::from(350));
+        assert_eq!(weight, crate::Weight::Other(350));
+        assert_eq!(crate::os2::Width::Condensed.to_percent(), 75.0);
+    }
+
+    #[test]
+    fn evaluates_embedding_permissions() {
+        use crate::os2::{FsType, Permissions, Purpose};
+
+        let font = abbvoice();
+        let fs_type = font.os2().unwrap().fs_type;
+        assert_eq!(fs_type.bits, 8);
+        assert_eq!(fs_type.usage, Permissions::Editable);
+        assert!(font.can_use(Purpose::Edit));
+        assert!(!font.can_use(Purpose::AppBundle));
+
+        let fs_type = FsType::from(0x0104);
+        assert_eq!(fs_type.usage, Permissions::PreviewAndPrint);
+        assert!(fs_type.can_use(Purpose::WebEmbed));
+        assert!(!fs_type.can_use(Purpose::PdfEmbedSubset));
+        assert!(!fs_type.can_use(Purpose::Edit));
+
+        let fs_type = FsType::from(0x0006);
+        assert!(fs_type.has_conflicting_usage);
+        assert_eq!(fs_type.usage, Permissions::PreviewAndPrint);
+        assert!(!FsType::from(0x0002).can_use(Purpose::WebEmbed));
+        assert!(!FsType::from(0x0200).can_use(Purpose::PdfEmbedSubset));
+        assert!(FsType::from(0).can_use(Purpose::AppBundle));
+    }
+
+    #[test]
+    fn exposes_style_and_decoration_metrics() {
+        let font = abbvoice();
+        let os2 = font.os2().unwrap();
+        assert_eq!(font.style(), crate::os2::Style::Normal);
+        assert_eq!(
+            font.subscript_metrics().unwrap().y_size,
+            os2.subscript_metrics.y_size
+        );
+        assert_eq!(
+            font.strikeout_metrics().unwrap().position,
+            os2.strikeout_metrics.position
+        );
+
+        let post = font.post().unwrap();
+        let underline = font.underline_metrics().unwrap();
+        assert_eq!(underline.position, post.underline_position);
+        assert_eq!(underline.thickness, post.underline_thickness);
+    }
+
+    /// Builds a format 0 `name` table from
+    /// `(platform ID, encoding ID, language ID, name ID, name)` records.
+    fn name_table(records: &[(u16, u16, u16, u16, &[u8])]) -> Vec<u8> {
+        let count = records.len() as u16;
+        let mut data: Vec<u8> = [0, count, 6 + 12 * count]
+            .into_iter()
+            .flat_map(u16::to_be_bytes)
+            .collect();
+        let mut strings = Vec::new();
+        for &(platform_id, encoding_id, language_id, name_id, name) in records {
+            let offset = strings.len() as u16;
+            let length = name.len() as u16;
+            for value in [
+                platform_id,
+                encoding_id,
+                language_id,
+                name_id,
+                length,
+                offset,
+            ] {
+                data.extend(u16::to_be_bytes(value));
+            }
+            strings.extend_from_slice(name);
+        }
+        data.extend(strings);
+        data
+    }
+
+    fn utf16_be(name: &str) -> Vec<u8> {
+        name.encode_utf16().flat_map(u16::to_be_bytes).collect()
+    }
+
+    #[test]
+    fn decodes_legacy_names() {
+        let data = name_table(&[
+            (1, 0, 0, 1, b"Caf\x8E"),                       // Mac Roman
+            (3, 2, 0x0411, 1, &[0x93, 0xFA, 0x96, 0x7B]),   // Windows ShiftJIS
+            (1, 6, 14, 1, &[0xB0, 0xF5, 0xDC, 0xEE, 0xE1]), // Mac Greek
+            (1, 0, 17, 1, b"\xDCstanbul"),                  // Mac Turkish
+            (1, 29, 25, 1, &[0xFC, 0x97, 0x64, 0x90]),      // Mac Central European
+            (1, 5, 10, 1, &[0x80, 0xF9, 0xEC, 0xE5, 0xED]), // Mac Hebrew
+            (1, 21, 22, 1, &[0xE4, 0xB7, 0xC2]),            // Mac Thai
+            (1, 5, 10, 1, &[0xC2]),                         // Mac Hebrew, private use
+            (0, 3, 0, 1, &[0x00, 0x41, 0x00]),              // Unicode, odd length
+        ]);
+
+        let table = ttf_parser::name::Table::parse(&data).unwrap();
+        let names = crate::name::Table::new(Some(table), &data).unwrap().names;
+        assert_eq!(names[0].name, "Café");
+        assert_eq!(names[0].raw, None);
+        assert_eq!(names[1].name, "日本");
+        assert_eq!(names[2].name, "Αθήνα");
+        assert_eq!(names[3].name, "İstanbul");
+        assert_eq!(names[4].name, "Łódź");
+        assert_eq!(names[5].name, "Äשלום");
+        assert_eq!(names[6].name, "ไทย");
+        assert_eq!(names[7].name, "");
+        assert_eq!(names[7].raw, Some(vec![0xC2]));
+        assert_eq!(names[8].raw, Some(vec![0x00, 0x41, 0x00]));
+    }
+
+    #[test]
+    fn resolves_localized_names() {
+        use crate::name::NameId;
+
+        let names = ["Sans", "Sans SV", "サンズ", "標準"].map(utf16_be);
+        let data = name_table(&[
+            (3, 1, 0x0409, 1, &names[0]),
+            (3, 1, 0x081D, 1, &names[1]),
+            (3, 1, 0x0411, 1, &names[2]),
+            (3, 1, 0x0411, 2, &names[3]),
+        ]);
+
+        let table = ttf_parser::name::Table::parse(&data).unwrap();
+        let table = crate::name::Table::new(Some(table), &data).unwrap();
+        assert_eq!(table.names[1].language_tag.as_deref(), Some("sv-FI"));
+
+        let name = |name_id, locales: &[&str]| {
+            table
+                .get_name(name_id, locales)
+                .map(|record| record.name.as_str())
         };
+        assert_eq!(name(NameId::Family, &["ja-JP", "en-US"]), Some("サンズ"));
+        assert_eq!(name(NameId::Family, &["sv-SE"]), Some("Sans SV"));
+        assert_eq!(name(NameId::Family, &["de-DE"]), Some("Sans"));
+        assert_eq!(name(NameId::Subfamily, &["sv-SE"]), Some("標準"));
+        assert_eq!(name(NameId::FullName, &["en-US"]), None);
+    }
 
-        Ok(parser)
+    #[test]
+    fn resolves_font_specific_names() {
+        use crate::name::NameId;
+
+        let data = name_table(&[
+            (3, 1, 0x0409, 256, b"\0W\0e\0i\0g\0h\0t"),
+            (3, 1, 0x0409, 257, b"\0S\0e\0t\0 \0A"),
+        ]);
+        let table = ttf_parser::name::Table::parse(&data).unwrap();
+        let table = crate::name::Table::new(Some(table), &data).unwrap();
+
+        assert_eq!(table.names[0].name_id, NameId::Unknown);
+        assert_eq!(table.names[0].id, 256);
+        assert_eq!(table.names[1].id, 257);
+        assert!(table.get_name(NameId::Unknown, &[]).is_none());
+        assert_eq!(table.name_by_id(256), Some("Weight"));
+        assert_eq!(table.name_by_id(257), Some("Set A"));
+        assert_eq!(table.name_by_id(258), None);
     }
 
-    // #[wasm_bindgen(js_name = style)]
-    // pub fn style(&self) -> tables::os2::Style {
-    //     if let Some(os2_value) = self.tables.os2 {
-    //         return os2_value.style;
-    //     }
-    //     tables::os2::Style::Normal
-    // }
-
-    /// Returns a bounding box that large enough to enclose any glyph from the face.
-    #[wasm_bindgen(js_name = globalBoundingBox, method)]
-    pub fn global_bounding_box(&self) -> tables::Rect {
-        self.tables.head.global_bbox.clone()
+    #[test]
+    fn resolves_best_names() {
+        let font = abbvoice();
+        assert_eq!(font.family_name().as_deref(), Some("ABBvoice"));
+        assert_eq!(font.subfamily_name().as_deref(), Some("Medium"));
+        assert_eq!(font.full_name().as_deref(), Some("ABBvoice Medium"));
+        assert_eq!(font.post_script_name().as_deref(), Some("ABBvoice-Medium"));
+        assert_eq!(font.typographic_family().as_deref(), Some("ABBvoice"));
+        assert_eq!(font.wws_family(), None);
+
+        // The legacy family, used when there is no typographic family. The Windows
+        // record wins over the Macintosh one, which says "ABBvoice".
+        let family = font.get_name(crate::name::NameId::Family, &[]);
+        assert_eq!(family.as_deref(), Some("ABBvoice Medium"));
     }
 
-    #[wasm_bindgen(getter)]
-    pub fn tables(&mut self) -> Tables {
-        self.tables.to_owned()
+    #[test]
+    fn decodes_name_language_tags() {
+        #[rustfmt::skip]
+        let data = [
+            0x00, 0x01, 0x00, 0x01, 0x00, 0x18, // format 1, 1 record
+            0x00, 0x03, 0x00, 0x01, 0x80, 0x00, 0x00, 0x01, 0x00, 0x08, 0x00, 0x00, // Windows Unicode
+            0x00, 0x01, 0x00, 0x0A, 0x00, 0x08, // 1 language tag
+            0x00, b'S', 0x00, b'a', 0x00, b'n', 0x00, b's',
+            0x00, b's', 0x00, b'v', 0x00, b'-', 0x00, b'S', 0x00, b'E',
+        ];
+        let table = ttf_parser::name::Table::parse(&data);
+        let table = crate::name::Table::new(table, &data).unwrap();
+        assert_eq!(table.version, 1);
+        assert_eq!(table.language_tags, ["sv-SE"]);
+        assert_eq!(table.names.len(), 1);
+
+        let record = &table.names[0];
+        assert_eq!(record.platform_id, crate::name::PlatformId::Windows);
+        assert_eq!(record.platform_id_raw, 3);
+        assert_eq!(record.encoding_id, 1);
+        assert_eq!(record.language_id, 0x8000);
+        assert_eq!(record.language_tag.as_deref(), Some("sv-SE"));
+        assert_eq!(record.name, "Sans");
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use std::fs;
+    #[test]
+    fn lists_table_directory() {
+        let font = abbvoice();
+        let directory = font.table_directory();
+        assert_eq!(directory.flavor, Some(Flavor::TrueType));
+        assert!(directory.tables.iter().any(|record| record.tag == "head"));
+    }
+
+    #[test]
+    fn returns_raw_tables() {
+        let font = abbvoice();
+        let head = font.raw_table("head").unwrap();
+        assert_eq!(head.len(), 54);
+        assert_eq!(&head[12..16], &[0x5F, 0x0F, 0x3C, 0xF5]);
+        assert!(font.raw_table("DSIG!").is_none());
+    }
+
+    #[test]
+    fn verifies_checksums() {
+        let bytes = fs::read("ABBvoice-Medium.ttf").unwrap();
+        let font = Font::new(bytes.clone(), 0).unwrap();
+        assert!(font.verify_checksums().is_valid);
+
+        let name = font
+            .table_directory()
+            .tables
+            .iter()
+            .find(|record| record.tag == "name");
+        let mut bytes = bytes;
+        bytes[name.unwrap().offset as usize] ^= 0xFF;
+
+        let font = Font::new(bytes, 0).unwrap();
+        let report = font.verify_checksums();
+        assert!(!report.is_valid);
+        assert_eq!(report.mismatches.len(), 1);
+        assert_eq!(report.mismatches[0].tag, "name");
+        assert_ne!(
+            report.check_sum_adjustment,
+            report.expected_check_sum_adjustment
+        );
+    }
+
+    #[test]
+    fn enumerates_faces() {
+        let bytes = fs::read("ABBvoice-Medium.ttf").unwrap();
+        assert_eq!(crate::faces_in_collection(&bytes), 1);
+        assert_eq!(crate::faces_in_collection(b"wOFF"), 0);
+
+        let faces = crate::parse_all(&bytes);
+        assert_eq!(faces.len(), 1);
+        let face = faces[0].as_ref().unwrap();
+        assert_eq!(face.index, 0);
+        assert_eq!(face.family.as_deref(), Some("ABBvoice"));
+        assert_eq!(face.subfamily.as_deref(), Some("Medium"));
+
+        let faces = crate::parse_all(b"wOFF");
+        assert_eq!(faces.len(), 1);
+        assert!(faces[0].is_err());
+    }
 
     #[test]
     fn it_works() {
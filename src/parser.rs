@@ -0,0 +1,57 @@
+use ttf_parser::FromData;
+
+/// A big-endian binary data reader.
+///
+/// `ttf-parser` doesn't expose its own, so this one is built on top of its
+/// [`FromData`] implementations.
+#[derive(Clone, Copy)]
+pub(crate) struct Stream<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Stream<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data, offset: 0 }
+    }
+
+    /// Reads a value at the specified offset.
+    pub fn read_at<T: FromData>(data: &'a [u8], offset: usize) -> Option<T> {
+        let mut s = Self::new(data);
+        s.advance(offset);
+        s.read::<T>()
+    }
+
+    pub fn skip<T: FromData>(&mut self) {
+        self.advance(T::SIZE);
+    }
+
+    pub fn advance(&mut self, len: usize) {
+        self.offset = self.offset.saturating_add(len);
+    }
+
+    pub fn read<T: FromData>(&mut self) -> Option<T> {
+        self.read_bytes(T::SIZE).and_then(T::parse)
+    }
+
+    pub fn read_bytes(&mut self, len: usize) -> Option<&'a [u8]> {
+        let end = self.offset.checked_add(len)?;
+        let bytes = self.data.get(self.offset..end)?;
+        self.offset = end;
+        Some(bytes)
+    }
+
+    /// Reads a 16.16 fixed-point number.
+    ///
+    /// Unlike [`ttf_parser::Fixed`], doesn't lose precision.
+    pub fn read_fixed(&mut self) -> Option<f64> {
+        self.read::<i32>().map(|n| f64::from(n) / 65536.0)
+    }
+
+    /// Reads a `Version16Dot16`, where `0x00005000` means 0.5.
+    pub fn read_version(&mut self) -> Option<f64> {
+        let major = self.read::<u16>()?;
+        let minor = self.read::<u16>()?;
+        Some(f64::from(major) + f64::from(minor >> 12) / 10.0)
+    }
+}
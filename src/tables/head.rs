@@ -1,12 +1,21 @@
 use super::Rect;
+use crate::parser::Stream;
+use serde::Serialize;
+#[cfg(feature = "wasm")]
+use tsify::Tsify;
 use ttf_parser::head;
-use wasm_bindgen::prelude::wasm_bindgen;
 
-#[wasm_bindgen]
-#[derive(Clone, Copy)]
+/// Seconds between 1904-01-01 and 1970-01-01.
+const MAC_EPOCH_OFFSET: i64 = 2_082_844_800;
+
+/// The largest Unix timestamp in seconds a JavaScript `Date` can represent.
+const MAX_TIMESTAMP: i64 = 8_640_000_000_000;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi))]
 pub enum IndexToLocationFormat {
-    Short = "Short",
-    Long = "Long",
+    Short,
+    Long,
 }
 
 impl From<head::IndexToLocationFormat> for IndexToLocationFormat {
@@ -18,28 +27,175 @@ impl From<head::IndexToLocationFormat> for IndexToLocationFormat {
     }
 }
 
+/// Decoded [`flags`](https://docs.microsoft.com/en-us/typography/opentype/spec/head#flags).
+#[derive(Clone, Copy, Debug, Serialize)]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi))]
+#[serde(rename_all = "camelCase")]
+pub struct Flags {
+    /// Raw flags.
+    pub bits: u16,
+    /// Bit 0: baseline for font at y=0.
+    pub baseline_at_y0: bool,
+    /// Bit 1: left sidebearing point at x=0.
+    pub left_sidebearing_at_x0: bool,
+    /// Bit 2: instructions may depend on point size.
+    pub instructions_depend_on_point_size: bool,
+    /// Bit 3: force ppem to integer values for all internal scaler math.
+    pub force_integer_ppem: bool,
+    /// Bit 4: instructions may alter advance width.
+    pub instructions_alter_advance_width: bool,
+    /// Bit 11: font data is "lossless" as a result of having been
+    /// subjected to optimizing transformation and/or compression.
+    pub lossless: bool,
+    /// Bit 12: font converted (produce compatible metrics).
+    pub converted: bool,
+    /// Bit 13: font optimized for ClearType.
+    pub optimized_for_clear_type: bool,
+    /// Bit 14: last resort font.
+    pub last_resort: bool,
+}
+
+impl From<u16> for Flags {
+    fn from(bits: u16) -> Flags {
+        let bit = |n: u16| bits & (1 << n) != 0;
+        Flags {
+            bits,
+            baseline_at_y0: bit(0),
+            left_sidebearing_at_x0: bit(1),
+            instructions_depend_on_point_size: bit(2),
+            force_integer_ppem: bit(3),
+            instructions_alter_advance_width: bit(4),
+            lossless: bit(11),
+            converted: bit(12),
+            optimized_for_clear_type: bit(13),
+            last_resort: bit(14),
+        }
+    }
+}
+
+/// Decoded [`macStyle`](https://docs.microsoft.com/en-us/typography/opentype/spec/head#macstyle).
+#[derive(Clone, Copy, Debug, Serialize)]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi))]
+pub struct MacStyle {
+    /// Raw style bits.
+    pub bits: u16,
+    pub bold: bool,
+    pub italic: bool,
+    pub underline: bool,
+    pub outline: bool,
+    pub shadow: bool,
+    pub condensed: bool,
+    pub extended: bool,
+}
+
+impl From<u16> for MacStyle {
+    fn from(bits: u16) -> MacStyle {
+        let bit = |n: u16| bits & (1 << n) != 0;
+        MacStyle {
+            bits,
+            bold: bit(0),
+            italic: bit(1),
+            underline: bit(2),
+            outline: bit(3),
+            shadow: bit(4),
+            condensed: bit(5),
+            extended: bit(6),
+        }
+    }
+}
+
 /// A [Font Header Table](https://docs.microsoft.com/en-us/typography/opentype/spec/head).
-#[wasm_bindgen(js_name = "HEADTable")]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, Serialize)]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi))]
+#[serde(rename = "HEADTable", rename_all = "camelCase")]
 pub struct Table {
+    /// Major version of the table.
+    pub major_version: u16,
+    /// Minor version of the table.
+    pub minor_version: u16,
+    /// Font revision set by the font manufacturer.
+    pub font_revision: f64,
+    /// Adjustment of the whole font checksum.
+    pub check_sum_adjustment: u32,
+    /// Decoded flags.
+    pub flags: Flags,
     /// Units per EM.
-    #[wasm_bindgen(js_name = "unitsPerEm")]
     pub units_per_em: u16,
+    /// Creation time as a Unix timestamp in seconds.
+    ///
+    /// A `Date` in JavaScript. `None` when out of the `Date` range.
+    #[cfg_attr(feature = "wasm", tsify(type = "Date | undefined"))]
+    pub created: Option<i64>,
+    /// Modification time as a Unix timestamp in seconds.
+    ///
+    /// A `Date` in JavaScript. `None` when out of the `Date` range.
+    #[cfg_attr(feature = "wasm", tsify(type = "Date | undefined"))]
+    pub modified: Option<i64>,
     /// A bounding box that large enough to enclose any glyph from the face.
-    #[wasm_bindgen(js_name = "globalBbox")]
     pub global_bbox: Rect,
+    /// Decoded Macintosh style.
+    pub mac_style: MacStyle,
+    /// Smallest readable size in pixels.
+    #[serde(rename = "lowestRecPPEM")]
+    pub lowest_rec_ppem: u16,
+    /// Deprecated font direction hint. Should be set to 2.
+    pub font_direction_hint: i16,
     /// An index format used by the [Index to Location Table](
     /// https://docs.microsoft.com/en-us/typography/opentype/spec/loca).
-    #[wasm_bindgen(js_name = "indexToLocationFormat")]
     pub index_to_location_format: IndexToLocationFormat,
+    /// Glyph data format. Should be set to 0.
+    pub glyph_data_format: i16,
 }
 
 impl Table {
-    pub fn new(table: head::Table) -> Self {
-        Self {
-            index_to_location_format: table.index_to_location_format.into(),
+    /// Parses the table from a raw data.
+    ///
+    /// `table` is the one already parsed by `ttf-parser`, which guarantees
+    /// that the data is long enough.
+    pub fn new(table: head::Table, data: &[u8]) -> Option<Self> {
+        let mut s = Stream::new(data);
+        let major_version = s.read::<u16>()?;
+        let minor_version = s.read::<u16>()?;
+        let font_revision = s.read_fixed()?;
+        let check_sum_adjustment = s.read::<u32>()?;
+        s.skip::<u32>(); // magic number
+        let flags = s.read::<u16>()?;
+        s.skip::<u16>(); // units per em
+
+        // `LONGDATETIME` is signed.
+        let created = s.read::<u64>()? as i64;
+        let modified = s.read::<u64>()? as i64;
+        s.advance(8); // bounding box
+        let mac_style = s.read::<u16>()?;
+        let lowest_rec_ppem = s.read::<u16>()?;
+        let font_direction_hint = s.read::<i16>()?;
+        s.skip::<i16>(); // index to location format
+        let glyph_data_format = s.read::<i16>()?;
+
+        Some(Self {
+            major_version,
+            minor_version,
+            font_revision,
+            check_sum_adjustment,
+            flags: flags.into(),
             units_per_em: table.units_per_em,
+            created: to_unix_timestamp(created),
+            modified: to_unix_timestamp(modified),
             global_bbox: table.global_bbox.into(),
-        }
+            mac_style: mac_style.into(),
+            lowest_rec_ppem,
+            font_direction_hint,
+            index_to_location_format: table.index_to_location_format.into(),
+            glyph_data_format,
+        })
     }
 }
+
+/// Converts a `LONGDATETIME` to a Unix timestamp.
+///
+/// Returns `None` when out of the JavaScript `Date` range.
+fn to_unix_timestamp(value: i64) -> Option<i64> {
+    value
+        .checked_sub(MAC_EPOCH_OFFSET)
+        .filter(|timestamp| (-MAX_TIMESTAMP..=MAX_TIMESTAMP).contains(timestamp))
+}
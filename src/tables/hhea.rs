@@ -1,29 +1,80 @@
+use crate::parser::Stream;
+use serde::Serialize;
+#[cfg(feature = "wasm")]
+use tsify::Tsify;
 use ttf_parser::hhea;
-use wasm_bindgen::prelude::wasm_bindgen;
 
 /// A [Horizontal Header Table](https://docs.microsoft.com/en-us/typography/opentype/spec/hhea).
-#[wasm_bindgen(js_name = "HHEATable")]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, Serialize)]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi))]
+#[serde(rename = "HHEATable", rename_all = "camelCase")]
 pub struct Table {
+    /// Major version of the table.
+    pub major_version: u16,
+    /// Minor version of the table.
+    pub minor_version: u16,
     /// Face ascender.
     pub ascender: i16,
     /// Face descender.
     pub descender: i16,
     /// Face line gap.
-    #[wasm_bindgen(js_name = "lineGap")]
     pub line_gap: i16,
+    /// Maximum advance width value in the `hmtx` table.
+    pub advance_width_max: u16,
+    /// Minimum left side bearing value in the `hmtx` table.
+    pub min_left_side_bearing: i16,
+    /// Minimum right side bearing value.
+    pub min_right_side_bearing: i16,
+    /// Maximum of left side bearing plus glyph width.
+    pub x_max_extent: i16,
+    /// Used to calculate the slope of the cursor (rise/run); 1 for vertical.
+    pub caret_slope_rise: i16,
+    /// 0 for vertical.
+    pub caret_slope_run: i16,
+    /// The amount by which a slanted highlight on a glyph needs to be shifted
+    /// to produce the best appearance. Set to 0 for non-slanted fonts.
+    pub caret_offset: i16,
+    /// 0 for current format.
+    pub metric_data_format: i16,
     /// Number of metrics in the `hmtx` table.
-    #[wasm_bindgen(js_name = "numberOfMetrics")]
     pub number_of_metrics: u16,
 }
 
 impl Table {
-    pub fn new(table: hhea::Table) -> Self {
-        Self {
+    /// Parses the table from a raw data.
+    ///
+    /// `table` is the one already parsed by `ttf-parser`, which guarantees
+    /// that the data is long enough.
+    pub fn new(table: hhea::Table, data: &[u8]) -> Option<Self> {
+        let mut s = Stream::new(data);
+        let major_version = s.read::<u16>()?;
+        let minor_version = s.read::<u16>()?;
+        s.advance(6); // ascender, descender and line gap
+        let advance_width_max = s.read::<u16>()?;
+        let min_left_side_bearing = s.read::<i16>()?;
+        let min_right_side_bearing = s.read::<i16>()?;
+        let x_max_extent = s.read::<i16>()?;
+        let caret_slope_rise = s.read::<i16>()?;
+        let caret_slope_run = s.read::<i16>()?;
+        let caret_offset = s.read::<i16>()?;
+        s.advance(8); // reserved
+        let metric_data_format = s.read::<i16>()?;
+
+        Some(Self {
+            major_version,
+            minor_version,
             ascender: table.ascender,
             descender: table.descender,
             line_gap: table.line_gap,
+            advance_width_max,
+            min_left_side_bearing,
+            min_right_side_bearing,
+            x_max_extent,
+            caret_slope_rise,
+            caret_slope_run,
+            caret_offset,
+            metric_data_format,
             number_of_metrics: table.number_of_metrics,
-        }
+        })
     }
 }
@@ -1,19 +1,86 @@
+use crate::parser::Stream;
+use serde::Serialize;
+#[cfg(feature = "wasm")]
+use tsify::Tsify;
 use ttf_parser::maxp;
-use wasm_bindgen::prelude::wasm_bindgen;
 
 /// A [Maximum Profile Table](https://docs.microsoft.com/en-us/typography/opentype/spec/maxp).
-#[wasm_bindgen(js_name = "MAXPTable")]
-#[derive(Clone, Copy)]
+///
+/// Fields other than `version` and `numberOfGlyphs` are present only in
+/// version 1.0, which is used by fonts with TrueType outlines.
+#[derive(Clone, Copy, Debug, Serialize)]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi))]
+#[serde(rename = "MAXPTable", rename_all = "camelCase")]
 pub struct Table {
+    /// Table version. Either 0.5 or 1.0.
+    pub version: f64,
     /// The total number of glyphs in the face.
-    #[wasm_bindgen(js_name = "numberOfGlyphs")]
     pub number_of_glyphs: u16,
+    /// Maximum points in a non-composite glyph.
+    pub max_points: Option<u16>,
+    /// Maximum contours in a non-composite glyph.
+    pub max_contours: Option<u16>,
+    /// Maximum points in a composite glyph.
+    pub max_composite_points: Option<u16>,
+    /// Maximum contours in a composite glyph.
+    pub max_composite_contours: Option<u16>,
+    /// 1 if instructions do not use the twilight zone, 2 otherwise.
+    pub max_zones: Option<u16>,
+    /// Maximum points used in the twilight zone.
+    pub max_twilight_points: Option<u16>,
+    /// Number of storage area locations.
+    pub max_storage: Option<u16>,
+    /// Number of function definitions.
+    pub max_function_defs: Option<u16>,
+    /// Number of instruction definitions.
+    pub max_instruction_defs: Option<u16>,
+    /// Maximum stack depth across the font program, the CVT program and
+    /// all glyph instructions.
+    pub max_stack_elements: Option<u16>,
+    /// Maximum byte count for glyph instructions.
+    pub max_size_of_instructions: Option<u16>,
+    /// Maximum number of components referenced at “top level” for any
+    /// composite glyph.
+    pub max_component_elements: Option<u16>,
+    /// Maximum levels of recursion; 1 for simple components.
+    pub max_component_depth: Option<u16>,
 }
 
 impl Table {
-    pub fn new(table: maxp::Table) -> Self {
-        Self {
+    /// Parses the table from a raw data.
+    ///
+    /// `table` is the one already parsed by `ttf-parser`, which guarantees
+    /// that the version and the number of glyphs are valid.
+    pub fn new(table: maxp::Table, data: &[u8]) -> Option<Self> {
+        let mut s = Stream::new(data);
+        let version = s.read_version()?;
+        s.skip::<u16>(); // number of glyphs
+
+        // Version 0.5 has no other fields.
+        let mut read = || {
+            if version == 1.0 {
+                s.read::<u16>()
+            } else {
+                None
+            }
+        };
+
+        Some(Self {
+            version,
             number_of_glyphs: table.number_of_glyphs.get(),
-        }
+            max_points: read(),
+            max_contours: read(),
+            max_composite_points: read(),
+            max_composite_contours: read(),
+            max_zones: read(),
+            max_twilight_points: read(),
+            max_storage: read(),
+            max_function_defs: read(),
+            max_instruction_defs: read(),
+            max_stack_elements: read(),
+            max_size_of_instructions: read(),
+            max_component_elements: read(),
+            max_component_depth: read(),
+        })
     }
 }
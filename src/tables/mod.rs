@@ -1,14 +1,25 @@
 pub mod head;
 pub mod hhea;
+mod language;
+mod mac_encoding;
 pub mod maxp;
 pub mod name;
 pub mod os2;
-
-use ttf_parser::{Face, LineMetrics as PLineMetrics, Rect as PRect, Weight as PWeight};
-use wasm_bindgen::prelude::*;
-
-#[wasm_bindgen]
-#[derive(Clone)]
+pub mod panose;
+pub mod post;
+pub mod vendor;
+pub mod vhea;
+pub mod vmtx;
+pub mod vorg;
+
+use serde::Serialize;
+#[cfg(feature = "wasm")]
+use tsify::Tsify;
+use ttf_parser::{LineMetrics as PLineMetrics, Rect as PRect, Weight as PWeight};
+
+/// All parsed tables, see [`Font::tables`](crate::Font::tables).
+#[derive(Clone, Debug, Serialize)]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi))]
 pub struct Tables {
     /// A [Font Header Table](https://docs.microsoft.com/en-us/typography/opentype/spec/head).
     pub head: crate::tables::head::Table,
@@ -22,38 +33,24 @@ pub struct Tables {
     /// A [OS/2 and Windows Metrics Table](https://docs.microsoft.com/en-us/typography/opentype/spec/os2).
     pub os2: Option<crate::tables::os2::Table>,
 
-    #[wasm_bindgen(getter_with_clone)]
-    /// https://docs.microsoft.com/en-us/typography/opentype/spec/name).
+    /// A [Naming Table](https://docs.microsoft.com/en-us/typography/opentype/spec/name).
     pub name: Option<crate::tables::name::Table>,
-}
-
-impl Tables {
-    pub fn new(face: &Face) -> Self {
-        let face_tables = face.tables();
 
-        let head = crate::tables::head::Table::new(face_tables.head);
-        let hhea = crate::tables::hhea::Table::new(face_tables.hhea);
-        let maxp = crate::tables::maxp::Table::new(face_tables.maxp);
+    /// A [PostScript Table](https://docs.microsoft.com/en-us/typography/opentype/spec/post).
+    pub post: Option<crate::tables::post::Table>,
 
-        let os2 = crate::tables::os2::Table::new(face_tables.os2);
-        let name = crate::tables::name::Table::new(face_tables.name);
+    /// A [Vertical Header Table](https://docs.microsoft.com/en-us/typography/opentype/spec/vhea).
+    pub vhea: Option<crate::tables::vhea::Table>,
 
-        Self {
-            head,
-            hhea,
-            maxp,
-            os2,
-            name,
-        }
-    }
-}
+    /// A [Vertical Metrics Table](https://docs.microsoft.com/en-us/typography/opentype/spec/vmtx).
+    pub vmtx: Option<crate::tables::vmtx::Table>,
 
-pub enum TablesEnum {
-    OS2,
+    /// A [Vertical Origin Table](https://docs.microsoft.com/en-us/typography/opentype/spec/vorg).
+    pub vorg: Option<crate::tables::vorg::Table>,
 }
 
-#[wasm_bindgen]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, Serialize)]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi))]
 pub struct LineMetrics {
     /// Line position.
     pub position: i16,
@@ -72,19 +69,19 @@ impl From<PLineMetrics> for LineMetrics {
 }
 
 /// A face [weight](https://docs.microsoft.com/en-us/typography/opentype/spec/os2#usweightclass).
-#[wasm_bindgen]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi))]
 pub enum Weight {
-    Thin = "Thin",
-    ExtraLight = "ExtraLight",
-    Light = "Light",
-    Normal = "Normal",
-    Medium = "Medium",
-    SemiBold = "SemiBold",
-    Bold = "Bold",
-    ExtraBold = "ExtraBold",
-    Black = "Black",
-    Other = "Other", // TODO: Other(u16),
+    Thin,
+    ExtraLight,
+    Light,
+    Normal,
+    Medium,
+    SemiBold,
+    Bold,
+    ExtraBold,
+    Black,
+    Other(u16),
 }
 
 impl From<PWeight> for Weight {
@@ -99,7 +96,7 @@ impl From<PWeight> for Weight {
             PWeight::Normal => Weight::Normal,
             PWeight::SemiBold => Weight::SemiBold,
             PWeight::Thin => Weight::Thin,
-            PWeight::Other(_) => Weight::Other,
+            PWeight::Other(n) => Weight::Other(n),
         }
     }
 }
@@ -107,8 +104,9 @@ impl From<PWeight> for Weight {
 /// A rectangle.
 ///
 /// Doesn't guarantee that `x_min` <= `x_max` and/or `y_min` <= `y_max`.
-#[wasm_bindgen]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, Serialize)]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi))]
+#[serde(rename_all = "camelCase")]
 pub struct Rect {
     pub x_min: i16,
     pub y_min: i16,
@@ -1,38 +1,44 @@
+use encoding_rs::Encoding;
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "wasm")]
 use tsify::Tsify;
-use ttf_parser::{name, LazyArray16};
-use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
+use ttf_parser::name;
+
+use super::language::{macintosh_language_tag, primary_language, windows_language_tag};
+use super::mac_encoding;
+use crate::parser::Stream;
 
 /// A [Name ID](https://docs.microsoft.com/en-us/typography/opentype/spec/name#name-ids).
-#[wasm_bindgen(skip_typescript)]
-#[derive(Clone, Tsify)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi, from_wasm_abi))]
 pub enum NameId {
-    CopyrightNotice = "CopyrightNotice",
-    Family = "Family",
-    Subfamily = "Subfamily",
-    UniqueId = "UniqueId",
-    FullName = "FullName",
-    Version = "Version",
-    PostScriptName = "PostScriptName",
-    Trademark = "Trademark",
-    Manufacturer = "Manufacturer",
-    Designer = "Designer",
-    Description = "Description",
-    VendorUrl = "VendorUrl",
-    DesignerUrl = "DesignerUrl",
-    License = "License",
-    LicenseUrl = "LicenseUrl",
+    CopyrightNotice,
+    Family,
+    Subfamily,
+    UniqueId,
+    FullName,
+    Version,
+    PostScriptName,
+    Trademark,
+    Manufacturer,
+    Designer,
+    Description,
+    VendorUrl,
+    DesignerUrl,
+    License,
+    LicenseUrl,
     // Reserved = "Reserved",
-    TypographicFamily = "TypographicFamily",
-    TypographicSubFamily = "TypographicSubFamily",
-    CompatibleFull = "CompatibleFull",
-    SampleText = "SampleText",
-    PostScriptCID = "PostScriptCID",
-    WWSFamily = "WWSFamily",
-    WWSSubFamily = "WWSSubFamily",
-    LightBackgroundPalette = "LightBackgroundPalette",
-    DarkBackgroundPalette = "DarkBackgroundPalette",
-    VariationsPostScriptNamePrefix = "VariationsPostScriptNamePrefix",
-    Unknown = "Unknown",
+    TypographicFamily,
+    TypographicSubFamily,
+    CompatibleFull,
+    SampleText,
+    PostScriptCID,
+    WWSFamily,
+    WWSSubFamily,
+    LightBackgroundPalette,
+    DarkBackgroundPalette,
+    VariationsPostScriptNamePrefix,
+    Unknown,
 }
 
 impl From<u16> for NameId {
@@ -71,15 +77,16 @@ impl From<u16> for NameId {
 }
 
 /// A [platform ID](https://docs.microsoft.com/en-us/typography/opentype/spec/name#platform-ids).
-#[wasm_bindgen(skip_typescript)]
-// #[derive(Clone, Copy)]
-#[derive(Clone, Tsify)]
+///
+/// Maps 1:1 to the IDs 0 to 4, records with other IDs are skipped by `ttf-parser`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi))]
 pub enum PlatformId {
-    Unicode = "Unicode",
-    Macintosh = "Macintosh",
-    Iso = "Iso",
-    Windows = "Windows",
-    Custom = "Custome",
+    Unicode = 0,
+    Macintosh = 1,
+    Iso = 2,
+    Windows = 3,
+    Custom = 4,
 }
 
 impl From<name::PlatformId> for PlatformId {
@@ -95,83 +102,375 @@ impl From<name::PlatformId> for PlatformId {
 }
 
 /// A [Name Record](https://docs.microsoft.com/en-us/typography/opentype/spec/name#name-records).
-#[wasm_bindgen(inspectable, skip_typescript, js_name = "INameRecord")]
-#[derive(Clone, Tsify)]
+#[derive(Clone, Debug, Serialize)]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi))]
+#[serde(rename_all = "camelCase")]
 pub struct NameRecord {
     /// A platform ID.
-    #[wasm_bindgen(js_name = "platformId")]
-    #[wasm_bindgen(getter_with_clone)]
     pub platform_id: PlatformId,
 
-    /// A language ID.
-    #[wasm_bindgen(js_name = "languageId")]
-    #[wasm_bindgen(getter_with_clone)]
-    pub language_id: String,
+    /// A numeric platform ID, the same as `platform_id`.
+    pub platform_id_raw: u16,
+
+    /// A platform-specific encoding ID.
+    pub encoding_id: u16,
+
+    /// A platform-specific language ID.
+    ///
+    /// IDs from `0x8000` and up refer to the language tags of a format 1 table.
+    pub language_id: u16,
+
+    /// A [BCP 47](https://www.rfc-editor.org/info/bcp47) language tag, e.g. `sv-SE`.
+    ///
+    /// `None` when the language is not known, e.g. for Unicode platform names.
+    pub language_tag: Option<String>,
 
     /// A [Name ID](https://docs.microsoft.com/en-us/typography/opentype/spec/name#name-ids).
-    #[wasm_bindgen(js_name = "nameId", getter_with_clone)]
     pub name_id: NameId,
 
-    #[wasm_bindgen(getter_with_clone)]
-    pub name: String,
-}
+    /// A numeric name ID.
+    ///
+    /// Font-specific IDs from 256 and up, referenced by `fvar`, `STAT` and other
+    /// tables, are `Unknown` in `name_id` and can be told apart only by this one.
+    pub id: u16,
 
-#[wasm_bindgen(typescript_custom_section)]
-const ITEXT_STYLE: &'static str = r#"
-type StringList = NameRecord[]
-"#;
+    /// A decoded name.
+    ///
+    /// Empty when the name cannot be decoded.
+    pub name: String,
 
-#[wasm_bindgen]
-extern "C" {
-    #[wasm_bindgen(typescript_type = "StringList")]
-    #[derive(Clone)]
-    pub type StringList;
+    /// Raw name bytes, set only when the name cannot be decoded.
+    pub raw: Option<Vec<u8>>,
 }
 
 /// A [Naming Table](https://docs.microsoft.com/en-us/typography/opentype/spec/name).
-#[wasm_bindgen(js_name = "NAMETable", inspectable)]
-#[derive(Clone)]
+#[derive(Clone, Debug, Serialize)]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi))]
+#[serde(rename = "NAMETable", rename_all = "camelCase")]
 pub struct Table {
-    #[wasm_bindgen(getter_with_clone, typescript_type = "StringList")]
-    pub names: StringList,
+    /// A table format, either 0 or 1.
+    pub version: u16,
+
+    pub names: Vec<NameRecord>,
+
+    /// [BCP 47](https://www.rfc-editor.org/info/bcp47) language tags referenced
+    /// by language IDs from `0x8000` and up.
+    ///
+    /// Empty unless the table format is 1.
+    pub language_tags: Vec<String>,
 }
 
 impl Table {
-    pub fn new(table: Option<name::Table>) -> Option<Self> {
-        let Some(table_names) = table else {
-            return None;
+    pub fn new(table: Option<name::Table>, data: &[u8]) -> Option<Self> {
+        let version = Stream::read_at::<u16>(data, 0).unwrap_or(0);
+
+        // ttf-parser expects the language tags of format 1 before the name records
+        // instead of after them, so parse the records as if it was format 0.
+        let format0_data;
+        let table = if version == 1 {
+            format0_data = [&[0, 0], &data[2..]].concat();
+            name::Table::parse(&format0_data)
+        } else {
+            table
         };
+        let table_names = table?;
+
+        let language_tags = if version == 1 {
+            parse_language_tags(data).unwrap_or_default()
+        } else {
+            Vec::new()
+        };
+
         let names = table_names
             .names
             .into_iter()
             .map(|v| {
-                let name: String = {
-                    if v.is_unicode() {
-                        let mut name: Vec<u16> = Vec::new();
-                        for c in LazyArray16::<u16>::new(v.name) {
-                            name.push(c);
-                        }
-
-                        String::from_utf16(&name).unwrap()
-                    } else {
-                        std::str::from_utf8(v.name).unwrap_or_default().to_string()
-                    }
+                let name = decode(v.platform_id, v.encoding_id, v.language_id, v.name);
+                let language_tag = match v.language_id.checked_sub(0x8000) {
+                    Some(index) => language_tags.get(usize::from(index)).cloned(),
+                    None => language_tag(v.platform_id, v.language_id).map(str::to_string),
                 };
 
+                let platform_id = PlatformId::from(v.platform_id);
+
                 NameRecord {
-                    platform_id: v.platform_id.into(),
-                    language_id: v.language().to_string(),
+                    platform_id,
+                    platform_id_raw: platform_id as u16,
+                    encoding_id: v.encoding_id,
+                    language_id: v.language_id,
+                    language_tag,
                     name_id: v.name_id.into(),
-                    name,
+                    id: v.name_id,
+                    raw: name.is_none().then(|| v.name.to_vec()),
+                    name: name.unwrap_or_default(),
                 }
             })
-            .map(JsValue::from)
-            .collect::<Vec<JsValue>>();
-
-        let test = StringList::from(serde_wasm_bindgen::to_value(&names).unwrap());
+            .collect();
 
         Some(Self {
-            names: StringList::from(JsValue::from(&names).unwrap()),
+            version,
+            names,
+            language_tags,
         })
     }
+
+    /// Returns a name record in the first of the preferred locales the font provides.
+    ///
+    /// Locales are matched by the whole tag first and then by the primary language
+    /// only, so `sv-SE` matches `sv-FI` when there is no Swedish name for Sweden.
+    /// Falls back to English and then to the first available name.
+    /// Windows names are preferred over Unicode and Macintosh ones.
+    ///
+    /// Names that cannot be decoded are skipped.
+    pub fn get_name(&self, name_id: NameId, preferred_locales: &[&str]) -> Option<&NameRecord> {
+        // Font-specific names cannot be told apart, use `get_name_by_id` instead.
+        if name_id == NameId::Unknown {
+            return None;
+        }
+
+        self.find_name(|v| v.name_id == name_id, preferred_locales)
+    }
+
+    /// Returns a name record by a numeric name ID in the first of the preferred
+    /// locales the font provides.
+    ///
+    /// Locales are matched the same way as in [`get_name`](Self::get_name).
+    pub fn get_name_by_id(&self, id: u16, preferred_locales: &[&str]) -> Option<&NameRecord> {
+        self.find_name(|v| v.id == id, preferred_locales)
+    }
+
+    /// Returns a name by a numeric name ID, preferring English and Windows names.
+    pub fn name_by_id(&self, id: u16) -> Option<&str> {
+        self.get_name_by_id(id, &["en-US"])
+            .map(|record| record.name.as_str())
+            .filter(|name| !name.is_empty())
+    }
+
+    fn find_name(
+        &self,
+        predicate: impl Fn(&NameRecord) -> bool,
+        preferred_locales: &[&str],
+    ) -> Option<&NameRecord> {
+        let mut records: Vec<&NameRecord> = self
+            .names
+            .iter()
+            .filter(|v| predicate(v) && v.raw.is_none())
+            .collect();
+        records.sort_by_key(|v| match v.platform_id {
+            PlatformId::Windows => 0,
+            PlatformId::Unicode => 1,
+            PlatformId::Macintosh => 2,
+            _ => 3,
+        });
+
+        let find = |matches: &dyn Fn(&str) -> bool| {
+            records
+                .iter()
+                .find(|v| v.language_tag.as_deref().is_some_and(matches))
+                .copied()
+        };
+        let same_language =
+            |a: &str, b: &str| primary_language(a).eq_ignore_ascii_case(primary_language(b));
+
+        preferred_locales
+            .iter()
+            .find_map(|locale| find(&|tag| tag.eq_ignore_ascii_case(locale)))
+            .or_else(|| {
+                preferred_locales
+                    .iter()
+                    .find_map(|locale| find(&|tag| same_language(tag, locale)))
+            })
+            .or_else(|| find(&|tag| tag.eq_ignore_ascii_case("en-US")))
+            .or_else(|| find(&|tag| same_language(tag, "en")))
+            .or_else(|| records.first().copied())
+    }
+
+    /// Returns a family name.
+    ///
+    /// Prefers the typographic family over the legacy one, which is limited to
+    /// four styles per family.
+    pub fn family_name(&self) -> Option<&str> {
+        self.best_name(NameId::TypographicFamily)
+            .or_else(|| self.best_name(NameId::Family))
+    }
+
+    /// Returns a subfamily name.
+    ///
+    /// Prefers the typographic subfamily over the legacy one.
+    pub fn subfamily_name(&self) -> Option<&str> {
+        self.best_name(NameId::TypographicSubFamily)
+            .or_else(|| self.best_name(NameId::Subfamily))
+    }
+
+    /// Returns a full name.
+    pub fn full_name(&self) -> Option<&str> {
+        self.best_name(NameId::FullName)
+    }
+
+    /// Returns a PostScript name.
+    pub fn post_script_name(&self) -> Option<&str> {
+        self.best_name(NameId::PostScriptName)
+    }
+
+    /// Returns a typographic family name.
+    ///
+    /// Unlike [`family_name`](Self::family_name), doesn't fall back to the legacy family.
+    pub fn typographic_family(&self) -> Option<&str> {
+        self.best_name(NameId::TypographicFamily)
+    }
+
+    /// Returns a WWS family name.
+    pub fn wws_family(&self) -> Option<&str> {
+        self.best_name(NameId::WWSFamily)
+    }
+
+    /// Returns an English name, preferring Windows names over Macintosh ones.
+    fn best_name(&self, name_id: NameId) -> Option<&str> {
+        self.get_name(name_id, &["en-US"])
+            .map(|record| record.name.as_str())
+            .filter(|name| !name.is_empty())
+    }
+}
+
+// https://docs.microsoft.com/en-us/typography/opentype/spec/name#naming-table-format-1
+/// Parses the language tags of a format 1 table.
+fn parse_language_tags(data: &[u8]) -> Option<Vec<String>> {
+    let count = Stream::read_at::<u16>(data, 2)?;
+    let storage_offset = usize::from(Stream::read_at::<u16>(data, 4)?);
+
+    // Language tags follow the name records.
+    let mut s = Stream::new(data);
+    s.advance(6 + usize::from(count) * 12);
+    let tags_count = s.read::<u16>()?;
+
+    (0..tags_count)
+        .map(|_| {
+            let length = usize::from(s.read::<u16>()?);
+            let offset = storage_offset + usize::from(s.read::<u16>()?);
+            decode_utf16(data.get(offset..offset + length)?)
+        })
+        .collect()
+}
+
+/// Returns a BCP 47 language tag of a name record.
+fn language_tag(platform_id: name::PlatformId, language_id: u16) -> Option<&'static str> {
+    match platform_id {
+        name::PlatformId::Macintosh => macintosh_language_tag(language_id),
+        name::PlatformId::Windows => windows_language_tag(language_id),
+        _ => None,
+    }
+}
+
+/// A way the name bytes are stored.
+enum NameEncoding {
+    /// UTF-16BE.
+    Utf16,
+    /// A single- or multi-byte encoding stored as is.
+    Bytes(&'static Encoding),
+    /// A multi-byte encoding stored as 16-bit big-endian code units, where
+    /// single-byte characters have a zero high byte.
+    Wide(&'static Encoding),
+    /// A Macintosh script encoding that `encoding_rs` doesn't support.
+    MacScript(&'static [char; 128]),
+}
+
+// https://docs.microsoft.com/en-us/typography/opentype/spec/name#platform-specific-encoding-and-language-ids-macintosh-platform-platform-id--1
+// https://docs.microsoft.com/en-us/typography/opentype/spec/name#platform-specific-encoding-and-language-ids-windows-platform-platform-id-3
+/// Returns the encoding of a name record.
+///
+/// Macintosh Roman names are decoded using the language-specific variant
+/// for Icelandic, Faroese, Turkish, Croatian and Romanian.
+///
+/// Returns `None` for encodings that are not supported, like the Macintosh
+/// Indic scripts, Vietnamese, Symbol and the rest of the scripts without an
+/// Apple mapping table.
+fn name_encoding(
+    platform_id: name::PlatformId,
+    encoding_id: u16,
+    language_id: u16,
+) -> Option<NameEncoding> {
+    use name::PlatformId;
+
+    let encoding = match (platform_id, encoding_id) {
+        (PlatformId::Unicode, _) => NameEncoding::Utf16,
+        (PlatformId::Macintosh, 0) => match language_id {
+            15 | 30 => NameEncoding::MacScript(&mac_encoding::ICELANDIC),
+            17 => NameEncoding::MacScript(&mac_encoding::TURKISH),
+            18 => NameEncoding::MacScript(&mac_encoding::CROATIAN),
+            37 => NameEncoding::MacScript(&mac_encoding::ROMANIAN),
+            _ => NameEncoding::Bytes(encoding_rs::MACINTOSH),
+        },
+        (PlatformId::Macintosh, 1) => NameEncoding::Bytes(encoding_rs::SHIFT_JIS),
+        (PlatformId::Macintosh, 2) => NameEncoding::Bytes(encoding_rs::BIG5),
+        (PlatformId::Macintosh, 3) => NameEncoding::Bytes(encoding_rs::EUC_KR),
+        (PlatformId::Macintosh, 4) => NameEncoding::MacScript(&mac_encoding::ARABIC),
+        (PlatformId::Macintosh, 5) => NameEncoding::MacScript(&mac_encoding::HEBREW),
+        (PlatformId::Macintosh, 6) => NameEncoding::MacScript(&mac_encoding::GREEK),
+        (PlatformId::Macintosh, 7) => NameEncoding::Bytes(encoding_rs::X_MAC_CYRILLIC),
+        (PlatformId::Macintosh, 21) => NameEncoding::MacScript(&mac_encoding::THAI),
+        (PlatformId::Macintosh, 25) => NameEncoding::Bytes(encoding_rs::GBK),
+        (PlatformId::Macintosh, 29) => NameEncoding::MacScript(&mac_encoding::CENTRAL_EUROPEAN),
+        (PlatformId::Iso, 0) => NameEncoding::Bytes(encoding_rs::UTF_8),
+        (PlatformId::Iso, 1) => NameEncoding::Utf16,
+        (PlatformId::Iso, 2) => NameEncoding::Bytes(encoding_rs::WINDOWS_1252),
+        // Symbol, Unicode BMP and Unicode full repertoire.
+        (PlatformId::Windows, 0 | 1 | 10) => NameEncoding::Utf16,
+        (PlatformId::Windows, 2) => NameEncoding::Wide(encoding_rs::SHIFT_JIS),
+        (PlatformId::Windows, 3) => NameEncoding::Wide(encoding_rs::GBK),
+        (PlatformId::Windows, 4) => NameEncoding::Wide(encoding_rs::BIG5),
+        (PlatformId::Windows, 5) => NameEncoding::Wide(encoding_rs::EUC_KR),
+        _ => return None,
+    };
+
+    Some(encoding)
+}
+
+/// Decodes name bytes.
+///
+/// Returns `None` when the encoding is not supported or the data is invalid.
+fn decode(
+    platform_id: name::PlatformId,
+    encoding_id: u16,
+    language_id: u16,
+    data: &[u8],
+) -> Option<String> {
+    let decode_bytes = |encoding: &'static Encoding, data: &[u8]| {
+        encoding
+            .decode_without_bom_handling_and_without_replacement(data)
+            .map(|name| name.into_owned())
+    };
+
+    match name_encoding(platform_id, encoding_id, language_id)? {
+        NameEncoding::Utf16 => decode_utf16(data),
+        NameEncoding::Bytes(encoding) => decode_bytes(encoding, data),
+        NameEncoding::Wide(encoding) => {
+            // Some fonts store the bytes as is.
+            if !data.len().is_multiple_of(2) {
+                return decode_bytes(encoding, data);
+            }
+
+            let bytes: Vec<u8> = data
+                .chunks_exact(2)
+                .flat_map(|c| if c[0] == 0 { &c[1..] } else { c })
+                .copied()
+                .collect();
+            decode_bytes(encoding, &bytes)
+        }
+        NameEncoding::MacScript(table) => mac_encoding::decode(table, data),
+    }
+}
+
+/// Decodes UTF-16BE bytes.
+///
+/// Returns `None` when the data has an odd length.
+fn decode_utf16(data: &[u8]) -> Option<String> {
+    if !data.len().is_multiple_of(2) {
+        return None;
+    }
+
+    let units: Vec<u16> = data
+        .chunks_exact(2)
+        .map(|c| u16::from_be_bytes([c[0], c[1]]))
+        .collect();
+    String::from_utf16(&units).ok()
 }
@@ -1,17 +1,207 @@
-use super::{LineMetrics, Weight};
+use super::{panose::Panose, vendor::Vendor, LineMetrics, Weight};
+use crate::parser::Stream;
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "wasm")]
+use tsify::Tsify;
 use ttf_parser::os2;
-use wasm_bindgen::prelude::wasm_bindgen;
+
+const X_AVG_CHAR_WIDTH_OFFSET: usize = 2;
+const WIDTH_CLASS_OFFSET: usize = 6;
+const TYPE_OFFSET: usize = 8;
+const FAMILY_CLASS_OFFSET: usize = 30;
+const PANOSE_OFFSET: usize = 32;
+const UNICODE_RANGES_OFFSET: usize = 42;
+const VENDOR_ID_OFFSET: usize = 58;
+const SELECTION_OFFSET: usize = 62;
+const FIRST_CHAR_INDEX_OFFSET: usize = 64;
+const LAST_CHAR_INDEX_OFFSET: usize = 66;
+const CODE_PAGE_RANGES_OFFSET: usize = 78;
+const DEFAULT_CHAR_OFFSET: usize = 90;
+const BREAK_CHAR_OFFSET: usize = 92;
+const MAX_CONTEXT_OFFSET: usize = 94;
+const LOWER_OPTICAL_POINT_SIZE_OFFSET: usize = 96;
+const UPPER_OPTICAL_POINT_SIZE_OFFSET: usize = 98;
+
+// https://docs.microsoft.com/en-us/typography/opentype/spec/os2#ur
+/// Unicode blocks indexed by the `ulUnicodeRange` bit. Bits 123-127 are reserved.
+const UNICODE_RANGE_NAMES: &[&[&str]] = &[
+    &["Basic Latin"],
+    &["Latin-1 Supplement"],
+    &["Latin Extended-A"],
+    &["Latin Extended-B"],
+    &[
+        "IPA Extensions",
+        "Phonetic Extensions",
+        "Phonetic Extensions Supplement",
+    ],
+    &["Spacing Modifier Letters", "Modifier Tone Letters"],
+    &[
+        "Combining Diacritical Marks",
+        "Combining Diacritical Marks Supplement",
+    ],
+    &["Greek and Coptic"],
+    &["Coptic"],
+    &[
+        "Cyrillic",
+        "Cyrillic Supplement",
+        "Cyrillic Extended-A",
+        "Cyrillic Extended-B",
+    ],
+    &["Armenian"],
+    &["Hebrew"],
+    &["Vai"],
+    &["Arabic", "Arabic Supplement"],
+    &["NKo"],
+    &["Devanagari"],
+    &["Bengali"],
+    &["Gurmukhi"],
+    &["Gujarati"],
+    &["Oriya"],
+    &["Tamil"],
+    &["Telugu"],
+    &["Kannada"],
+    &["Malayalam"],
+    &["Thai"],
+    &["Lao"],
+    &["Georgian", "Georgian Supplement"],
+    &["Balinese"],
+    &["Hangul Jamo"],
+    &[
+        "Latin Extended Additional",
+        "Latin Extended-C",
+        "Latin Extended-D",
+    ],
+    &["Greek Extended"],
+    &["General Punctuation", "Supplemental Punctuation"],
+    &["Superscripts And Subscripts"],
+    &["Currency Symbols"],
+    &["Combining Diacritical Marks For Symbols"],
+    &["Letterlike Symbols"],
+    &["Number Forms"],
+    &[
+        "Arrows",
+        "Supplemental Arrows-A",
+        "Supplemental Arrows-B",
+        "Miscellaneous Symbols and Arrows",
+    ],
+    &[
+        "Mathematical Operators",
+        "Supplemental Mathematical Operators",
+        "Miscellaneous Mathematical Symbols-A",
+        "Miscellaneous Mathematical Symbols-B",
+    ],
+    &["Miscellaneous Technical"],
+    &["Control Pictures"],
+    &["Optical Character Recognition"],
+    &["Enclosed Alphanumerics"],
+    &["Box Drawing"],
+    &["Block Elements"],
+    &["Geometric Shapes"],
+    &["Miscellaneous Symbols"],
+    &["Dingbats"],
+    &["CJK Symbols And Punctuation"],
+    &["Hiragana"],
+    &["Katakana", "Katakana Phonetic Extensions"],
+    &["Bopomofo", "Bopomofo Extended"],
+    &["Hangul Compatibility Jamo"],
+    &["Phags-pa"],
+    &["Enclosed CJK Letters And Months"],
+    &["CJK Compatibility"],
+    &["Hangul Syllables"],
+    &["Non-Plane 0"],
+    &["Phoenician"],
+    &[
+        "CJK Unified Ideographs",
+        "CJK Radicals Supplement",
+        "Kangxi Radicals",
+        "Ideographic Description Characters",
+        "CJK Unified Ideographs Extension A",
+        "CJK Unified Ideographs Extension B",
+        "Kanbun",
+    ],
+    &["Private Use Area (plane 0)"],
+    &[
+        "CJK Strokes",
+        "CJK Compatibility Ideographs",
+        "CJK Compatibility Ideographs Supplement",
+    ],
+    &["Alphabetic Presentation Forms"],
+    &["Arabic Presentation Forms-A"],
+    &["Combining Half Marks"],
+    &["Vertical Forms", "CJK Compatibility Forms"],
+    &["Small Form Variants"],
+    &["Arabic Presentation Forms-B"],
+    &["Halfwidth And Fullwidth Forms"],
+    &["Specials"],
+    &["Tibetan"],
+    &["Syriac"],
+    &["Thaana"],
+    &["Sinhala"],
+    &["Myanmar"],
+    &["Ethiopic", "Ethiopic Supplement", "Ethiopic Extended"],
+    &["Cherokee"],
+    &["Unified Canadian Aboriginal Syllabics"],
+    &["Ogham"],
+    &["Runic"],
+    &["Khmer", "Khmer Symbols"],
+    &["Mongolian"],
+    &["Braille Patterns"],
+    &["Yi Syllables", "Yi Radicals"],
+    &["Tagalog", "Hanunoo", "Buhid", "Tagbanwa"],
+    &["Old Italic"],
+    &["Gothic"],
+    &["Deseret"],
+    &[
+        "Byzantine Musical Symbols",
+        "Musical Symbols",
+        "Ancient Greek Musical Notation",
+    ],
+    &["Mathematical Alphanumeric Symbols"],
+    &["Private Use (plane 15)", "Private Use (plane 16)"],
+    &["Variation Selectors", "Variation Selectors Supplement"],
+    &["Tags"],
+    &["Limbu"],
+    &["Tai Le"],
+    &["New Tai Lue"],
+    &["Buginese"],
+    &["Glagolitic"],
+    &["Tifinagh"],
+    &["Yijing Hexagram Symbols"],
+    &["Syloti Nagri"],
+    &["Linear B Syllabary", "Linear B Ideograms", "Aegean Numbers"],
+    &["Ancient Greek Numbers"],
+    &["Ugaritic"],
+    &["Old Persian"],
+    &["Shavian"],
+    &["Osmanya"],
+    &["Cypriot Syllabary"],
+    &["Kharoshthi"],
+    &["Tai Xuan Jing Symbols"],
+    &["Cuneiform", "Cuneiform Numbers and Punctuation"],
+    &["Counting Rod Numerals"],
+    &["Sundanese"],
+    &["Lepcha"],
+    &["Ol Chiki"],
+    &["Saurashtra"],
+    &["Kayah Li"],
+    &["Rejang"],
+    &["Cham"],
+    &["Ancient Symbols"],
+    &["Phaistos Disc"],
+    &["Carian", "Lycian", "Lydian"],
+    &["Domino Tiles", "Mahjong Tiles"],
+];
 
 /// A face style.
-#[wasm_bindgen]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi))]
 pub enum Style {
     /// A face that is neither italic not obliqued.
-    Normal = "Normal",
+    Normal,
     /// A form that is generally cursive in nature.
-    Italic = "Italic",
+    Italic,
     /// A typically-sloped version of the regular face.
-    Oblique = "Oblique",
+    Oblique,
 }
 
 impl From<os2::Style> for Style {
@@ -24,14 +214,14 @@ impl From<os2::Style> for Style {
     }
 }
 
-#[wasm_bindgen]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi))]
 pub enum Permissions {
-    Installable = "Installable",
-    Restricted = "Restricted",
-    PreviewAndPrint = "PreviewAndPrint",
-    Editable = "Editable",
-    Malformed = "Malformed",
+    Installable,
+    Restricted,
+    PreviewAndPrint,
+    Editable,
+    Malformed,
 }
 
 impl From<Option<os2::Permissions>> for Permissions {
@@ -49,19 +239,134 @@ impl From<Option<os2::Permissions>> for Permissions {
     }
 }
 
+/// Decoded [`fsType`](https://docs.microsoft.com/en-us/typography/opentype/spec/os2#fstype)
+/// embedding permissions.
+#[derive(Clone, Copy, Debug, Serialize)]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi))]
+#[serde(rename_all = "camelCase")]
+pub struct FsType {
+    /// Raw flags.
+    pub bits: u16,
+
+    /// Usage permissions from bits 0-3.
+    ///
+    /// When several bits are set, which is invalid since version 3,
+    /// the least restrictive one is used.
+    pub usage: Permissions,
+
+    /// Bit 8: the font may not be subsetted prior to embedding.
+    pub no_subsetting: bool,
+
+    /// Bit 9: only bitmaps contained in the font may be embedded.
+    pub bitmap_embedding_only: bool,
+
+    /// Checks that more than one usage bit is set.
+    pub has_conflicting_usage: bool,
+}
+
+impl From<u16> for FsType {
+    fn from(bits: u16) -> FsType {
+        let usage = if bits & 0x0008 != 0 {
+            Permissions::Editable
+        } else if bits & 0x0004 != 0 {
+            Permissions::PreviewAndPrint
+        } else if bits & 0x0002 != 0 {
+            Permissions::Restricted
+        } else {
+            Permissions::Installable
+        };
+
+        FsType {
+            bits,
+            usage,
+            no_subsetting: bits & 0x0100 != 0,
+            bitmap_embedding_only: bits & 0x0200 != 0,
+            has_conflicting_usage: (bits & 0x000E).count_ones() > 1,
+        }
+    }
+}
+
+impl FsType {
+    /// Checks that the font may be used for the specified purpose.
+    pub fn can_use(&self, purpose: Purpose) -> bool {
+        let embeddable = self.usage != Permissions::Restricted && !self.bitmap_embedding_only;
+        match purpose {
+            Purpose::WebEmbed => embeddable,
+            Purpose::PdfEmbedSubset => embeddable && !self.no_subsetting,
+            Purpose::AppBundle => self.usage == Permissions::Installable,
+            Purpose::Edit => matches!(self.usage, Permissions::Installable | Permissions::Editable),
+        }
+    }
+}
+
+/// A way of using a font, checked against its embedding permissions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi, from_wasm_abi))]
+pub enum Purpose {
+    /// Serving the font to browsers with `@font-face`.
+    ///
+    /// Requires outline embedding that is not restricted.
+    WebEmbed,
+    /// Embedding a subset of the font into a PDF document.
+    ///
+    /// Requires outline embedding that is not restricted and allows subsetting.
+    PdfEmbedSubset,
+    /// Shipping the font inside an application that installs it.
+    ///
+    /// Requires installable embedding.
+    AppBundle,
+    /// Embedding the font into documents that can be edited.
+    ///
+    /// Requires installable or editable embedding.
+    Edit,
+}
+
 /// A face [width](https://docs.microsoft.com/en-us/typography/opentype/spec/os2#uswidthclass).
-#[wasm_bindgen]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi))]
 pub enum Width {
-    UltraCondensed = "UltraCondensed",
-    ExtraCondensed = "ExtraCondensed",
-    Condensed = "Condensed",
-    SemiCondensed = "SemiCondensed",
-    Normal = "Normal",
-    SemiExpanded = "SemiExpanded",
-    Expanded = "Expanded",
-    ExtraExpanded = "ExtraExpanded",
-    UltraExpanded = "UltraExpanded",
+    UltraCondensed,
+    ExtraCondensed,
+    Condensed,
+    SemiCondensed,
+    Normal,
+    SemiExpanded,
+    Expanded,
+    ExtraExpanded,
+    UltraExpanded,
+}
+
+impl Width {
+    /// Returns the width as a percentage of the normal width, as used by
+    /// the CSS `font-stretch` property.
+    pub fn to_percent(self) -> f64 {
+        match self {
+            Width::UltraCondensed => 50.0,
+            Width::ExtraCondensed => 62.5,
+            Width::Condensed => 75.0,
+            Width::SemiCondensed => 87.5,
+            Width::Normal => 100.0,
+            Width::SemiExpanded => 112.5,
+            Width::Expanded => 125.0,
+            Width::ExtraExpanded => 150.0,
+            Width::UltraExpanded => 200.0,
+        }
+    }
+
+    /// Returns the CSS `font-stretch` keyword.
+    pub fn to_css(self) -> &'static str {
+        match self {
+            Width::UltraCondensed => "ultra-condensed",
+            Width::ExtraCondensed => "extra-condensed",
+            Width::Condensed => "condensed",
+            Width::SemiCondensed => "semi-condensed",
+            Width::Normal => "normal",
+            Width::SemiExpanded => "semi-expanded",
+            Width::Expanded => "expanded",
+            Width::ExtraExpanded => "extra-expanded",
+            Width::UltraExpanded => "ultra-expanded",
+        }
+    }
 }
 
 impl From<os2::Width> for Width {
@@ -80,8 +385,9 @@ impl From<os2::Width> for Width {
     }
 }
 
-#[wasm_bindgen(inspectable)]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, Serialize)]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi))]
+#[serde(rename_all = "camelCase")]
 pub struct ScriptMetrics {
     /// Horizontal face size.
     pub x_size: i16,
@@ -107,92 +413,374 @@ impl From<os2::ScriptMetrics> for ScriptMetrics {
     }
 }
 
+// https://docs.microsoft.com/en-us/typography/opentype/spec/os2#cpr
+/// Code pages by the `ulCodePageRange` bit. Bits that are not listed are reserved.
+const CODE_PAGES: &[(u8, Option<u16>, &str)] = &[
+    (0, Some(1252), "Latin 1"),
+    (1, Some(1250), "Latin 2: Eastern Europe"),
+    (2, Some(1251), "Cyrillic"),
+    (3, Some(1253), "Greek"),
+    (4, Some(1254), "Turkish"),
+    (5, Some(1255), "Hebrew"),
+    (6, Some(1256), "Arabic"),
+    (7, Some(1257), "Windows Baltic"),
+    (8, Some(1258), "Vietnamese"),
+    (16, Some(874), "Thai"),
+    (17, Some(932), "JIS/Japan"),
+    (
+        18,
+        Some(936),
+        "Chinese: Simplified chars--PRC and Singapore",
+    ),
+    (19, Some(949), "Korean Wansung"),
+    (
+        20,
+        Some(950),
+        "Chinese: Traditional chars--Taiwan and Hong Kong",
+    ),
+    (21, Some(1361), "Korean Johab"),
+    (29, None, "Macintosh Character Set (US Roman)"),
+    (30, None, "OEM Character Set"),
+    (31, None, "Symbol Character Set"),
+    (48, Some(869), "IBM Greek"),
+    (49, Some(866), "MS-DOS Russian"),
+    (50, Some(865), "MS-DOS Nordic"),
+    (51, Some(864), "Arabic"),
+    (52, Some(863), "MS-DOS Canadian French"),
+    (53, Some(862), "Hebrew"),
+    (54, Some(861), "MS-DOS Icelandic"),
+    (55, Some(860), "MS-DOS Portuguese"),
+    (56, Some(857), "IBM Turkish"),
+    (57, Some(855), "IBM Cyrillic; primarily Russian"),
+    (58, Some(852), "Latin 2"),
+    (59, Some(775), "MS-DOS Baltic"),
+    (60, Some(737), "Greek; former 437 G"),
+    (61, Some(708), "Arabic; ASMO 708"),
+    (62, Some(850), "WE/Latin 1"),
+    (63, Some(437), "US"),
+];
+
+/// [Unicode ranges](https://docs.microsoft.com/en-us/typography/opentype/spec/os2#ur)
+/// declared by the face.
+#[derive(Clone, Debug, Serialize)]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi))]
+pub struct UnicodeRanges {
+    /// Raw `ulUnicodeRange1` to `ulUnicodeRange4`.
+    pub bits: [u32; 4],
+
+    /// Names of the declared Unicode blocks.
+    ///
+    /// A single bit can cover several blocks, e.g. bit 9 covers
+    /// "Cyrillic" and "Cyrillic Supplement" among others.
+    pub names: Vec<String>,
+}
+
+impl UnicodeRanges {
+    fn new(bits: [u32; 4]) -> Self {
+        let names = UNICODE_RANGE_NAMES
+            .iter()
+            .enumerate()
+            .filter(|(bit, _)| bits[bit / 32] & (1 << (bit % 32)) != 0)
+            .flat_map(|(_, names)| names.iter())
+            .map(|name| name.to_string())
+            .collect();
+
+        Self { bits, names }
+    }
+
+    /// Checks that the specified range bit is set.
+    pub fn contains(&self, bit: u8) -> bool {
+        bit < 128 && self.bits[usize::from(bit / 32)] & (1 << (bit % 32)) != 0
+    }
+}
+
+/// [Code page character ranges](https://docs.microsoft.com/en-us/typography/opentype/spec/os2#cpr)
+/// declared by the face.
+#[derive(Clone, Debug, Serialize)]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi))]
+#[serde(rename_all = "camelCase")]
+pub struct CodePageRanges {
+    /// Raw `ulCodePageRange1` and `ulCodePageRange2`.
+    pub bits: [u32; 2],
+
+    /// Names of the declared code pages.
+    pub names: Vec<String>,
+
+    /// Windows code page numbers of the declared code pages, e.g. 1252 for Latin 1.
+    ///
+    /// The Macintosh, OEM and Symbol character sets have no number.
+    pub code_pages: Vec<u16>,
+}
+
+impl CodePageRanges {
+    fn new(bits: [u32; 2]) -> Self {
+        let code_pages = CODE_PAGES
+            .iter()
+            .filter(|(bit, ..)| bits[usize::from(bit / 32)] & (1 << (bit % 32)) != 0);
+
+        Self {
+            bits,
+            names: code_pages
+                .clone()
+                .map(|(.., name)| name.to_string())
+                .collect(),
+            code_pages: code_pages
+                .filter_map(|(_, code_page, _)| *code_page)
+                .collect(),
+        }
+    }
+
+    /// Checks that the specified code page bit is set.
+    pub fn contains(&self, bit: u8) -> bool {
+        bit < 64 && self.bits[usize::from(bit / 32)] & (1 << (bit % 32)) != 0
+    }
+}
+
+/// Decoded [`fsSelection`](https://docs.microsoft.com/en-us/typography/opentype/spec/os2#fsselection).
+#[derive(Clone, Copy, Debug, Serialize)]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi))]
+#[serde(rename_all = "camelCase")]
+pub struct Selection {
+    /// Raw flags.
+    pub bits: u16,
+    /// Bit 0: font contains italic or oblique glyphs.
+    pub italic: bool,
+    /// Bit 1: glyphs are underscored.
+    pub underscore: bool,
+    /// Bit 2: glyphs have their foreground and background reversed.
+    pub negative: bool,
+    /// Bit 3: outline (hollow) glyphs.
+    pub outlined: bool,
+    /// Bit 4: glyphs are overstruck.
+    pub strikeout: bool,
+    /// Bit 5: glyphs are emboldened.
+    pub bold: bool,
+    /// Bit 6: glyphs are in the standard weight/style for the font.
+    pub regular: bool,
+    /// Bit 7: typographic metrics should be used for line spacing.
+    pub use_typo_metrics: bool,
+    /// Bit 8: the font has name table strings consistent with a
+    /// weight/width/slope family.
+    pub wws: bool,
+    /// Bit 9: font contains oblique glyphs.
+    pub oblique: bool,
+}
+
+impl From<u16> for Selection {
+    fn from(bits: u16) -> Selection {
+        let bit = |n: u16| bits & (1 << n) != 0;
+        Selection {
+            bits,
+            italic: bit(0),
+            underscore: bit(1),
+            negative: bit(2),
+            outlined: bit(3),
+            strikeout: bit(4),
+            bold: bit(5),
+            regular: bit(6),
+            use_typo_metrics: bit(7),
+            wws: bit(8),
+            oblique: bit(9),
+        }
+    }
+}
+
+/// A range of point sizes the font was designed for.
+#[derive(Clone, Copy, Debug, Serialize)]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi))]
+pub struct OpticalPointSizeRange {
+    /// The lowest size in points, inclusive.
+    pub lower: f64,
+    /// The highest size in points, exclusive.
+    pub upper: f64,
+}
+
 /// A [OS/2 and Windows Metrics Table](https://docs.microsoft.com/en-us/typography/opentype/spec/os2).
-#[wasm_bindgen(js_name = "OS2Table", getter_with_clone, inspectable)]
-#[derive(Clone, Copy)]
+#[derive(Clone, Debug, Serialize)]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi))]
+#[serde(rename = "OS2Table", rename_all = "camelCase")]
 pub struct Table {
+    /// Returns table version.
+    pub version: u16,
+
+    /// Returns average width of all non-zero width glyphs.
+    pub x_avg_char_width: i16,
+
     /// Returns weight class.
     pub weight: Weight,
 
+    /// Returns raw weight class, usually in a 100..=900 range.
+    pub weight_class: u16,
+
+    /// Returns CSS `font-weight` value.
+    ///
+    /// The weight class clamped to a 1..=1000 range.
+    pub css_font_weight: String,
+
     /// Returns face width.
+    ///
+    /// Invalid width classes are treated as `Normal`.
     pub width: Width,
 
+    /// Returns raw width class, in a 1..=9 range for valid fonts.
+    pub width_class: u16,
+
+    /// Returns width as a percentage of the normal width, in a 50..=200 range.
+    pub width_percent: f64,
+
+    /// Returns CSS `font-stretch` keyword.
+    pub css_font_stretch: String,
+
     /// Returns face permissions.
     pub permissions: Permissions,
 
+    /// Returns decoded `fsType` embedding permissions.
+    pub fs_type: FsType,
+
     /// Checks if the face subsetting is allowed.
-    #[wasm_bindgen(js_name = "isSubsettingAllowed")]
     pub is_subsetting_allowed: bool,
 
     /// Checks if the face bitmaps embedding is allowed.
-    #[wasm_bindgen(js_name = "isBitmapEmbeddingAllowed")]
     pub is_bitmap_embedding_allowed: bool,
 
     /// Returns subscript metrics.
-    #[wasm_bindgen(js_name = "subscriptMetrics")]
     pub subscript_metrics: ScriptMetrics,
 
     /// Returns superscript metrics.
-    #[wasm_bindgen(js_name = "superscriptMetrics")]
     pub superscript_metrics: ScriptMetrics,
 
     /// Returns strikeout metrics.
-    #[wasm_bindgen(js_name = "strikeoutMetrics")]
     pub strikeout_metrics: LineMetrics,
 
-    // pub unicode_ranges: u128,
+    /// Returns IBM font family class and subclass.
+    pub family_class: i16,
+
+    /// Returns PANOSE classification.
+    pub panose: Panose,
+
+    /// Returns Unicode ranges.
+    pub unicode_ranges: UnicodeRanges,
+
+    /// Returns code page ranges.
+    ///
+    /// Returns `None` when the table version is 0.
+    pub code_page_ranges: Option<CodePageRanges>,
+
+    /// Returns font vendor ID (`achVendID`) without the space or NUL padding.
+    pub vendor_id: String,
+
+    /// Returns the registered vendor matching `vendor_id`.
+    pub vendor: Option<Vendor>,
+
+    /// Returns decoded `fsSelection` flags.
+    pub fs_selection: Selection,
+
+    /// Returns the minimum Unicode index in the face, capped at 0xFFFF.
+    pub first_char_index: u16,
+
+    /// Returns the maximum Unicode index in the face, capped at 0xFFFF.
+    pub last_char_index: u16,
+
     /// Returns style.
     pub style: Style,
 
     /// Checks if face is bold.
-    #[wasm_bindgen(js_name = "isBold")]
     pub is_bold: bool,
 
     /// Checks if typographic metrics should be used.
-    #[wasm_bindgen(js_name = "useTypographicMetrics")]
     pub use_typographic_metrics: bool,
 
     /// Returns typographic ascender.
-    #[wasm_bindgen(js_name = "typographicAscender")]
     pub typographic_ascender: i16,
 
     /// Returns typographic descender.
-    #[wasm_bindgen(js_name = "typographicDescender")]
     pub typographic_descender: i16,
 
     /// Returns typographic line gap.
-    #[wasm_bindgen(js_name = "typographicLineGap")]
     pub typographic_line_gap: i16,
 
     /// Returns Windows ascender.
-    #[wasm_bindgen(js_name = "windowsAscender")]
     pub windows_ascender: i16,
 
     /// Returns Windows descender.
-    #[wasm_bindgen(js_name = "windowsDescender")]
     pub windows_descender: i16,
 
     /// Returns x height.
-    #[wasm_bindgen(js_name = "xHeight")]
     pub x_height: Option<i16>,
 
     /// Returns capital height.
-    #[wasm_bindgen(js_name = "capitalHeight")]
     pub capital_height: Option<i16>,
+
+    /// Returns the character used for characters that are not in the face.
+    ///
+    /// Returns `None` when the table version is < 2.
+    pub default_char: Option<u16>,
+
+    /// Returns the character used as the word break.
+    ///
+    /// Returns `None` when the table version is < 2.
+    pub break_char: Option<u16>,
+
+    /// Returns the maximum length of a target glyph context for any feature.
+    ///
+    /// Returns `None` when the table version is < 2.
+    pub max_context: Option<u16>,
+
+    /// Returns the point size range the face was designed for.
+    ///
+    /// Returns `None` when the table version is < 5.
+    pub optical_point_size_range: Option<OpticalPointSizeRange>,
 }
 
 impl Table {
-    pub fn new(table: Option<os2::Table>) -> Option<Self> {
+    /// Parses the table from a raw data.
+    ///
+    /// `table` is the one parsed by `ttf-parser`, which is `None` when the
+    /// table is missing or malformed.
+    pub fn new(table: Option<os2::Table>, data: &[u8]) -> Option<Self> {
         if let Some(table) = table {
+            let read_u16 = |offset| Stream::read_at::<u16>(data, offset).unwrap_or(0);
+            let read_i16 = |offset| Stream::read_at::<i16>(data, offset).unwrap_or(0);
+            let x_avg_char_width = read_i16(X_AVG_CHAR_WIDTH_OFFSET);
             let weight = table.weight();
             let width = table.width();
+            let width_class = read_u16(WIDTH_CLASS_OFFSET);
             let permissions: Permissions = table.permissions().into();
+            let fs_type = read_u16(TYPE_OFFSET);
             let is_subsetting_allowed = table.is_subsetting_allowed();
             let is_bitmap_embedding_allowed = table.is_bitmap_embedding_allowed();
             let subscript_metrics = table.subscript_metrics();
             let superscript_metrics = table.superscript_metrics();
             let strikeout_metrics = table.strikeout_metrics();
-            // let unicode_ranges = table.unicode_ranges(); TODO
+            let family_class = read_i16(FAMILY_CLASS_OFFSET);
+            let mut panose = [0; 10];
+            if let Some(bytes) = data.get(PANOSE_OFFSET..PANOSE_OFFSET + 10) {
+                panose.copy_from_slice(bytes);
+            }
+            let read_u32 = |offset| Stream::read_at::<u32>(data, offset).unwrap_or(0);
+            let unicode_ranges = UnicodeRanges::new([
+                read_u32(UNICODE_RANGES_OFFSET),
+                read_u32(UNICODE_RANGES_OFFSET + 4),
+                read_u32(UNICODE_RANGES_OFFSET + 8),
+                read_u32(UNICODE_RANGES_OFFSET + 12),
+            ]);
+            let code_page_ranges = (table.version >= 1).then(|| {
+                CodePageRanges::new([
+                    read_u32(CODE_PAGE_RANGES_OFFSET),
+                    read_u32(CODE_PAGE_RANGES_OFFSET + 4),
+                ])
+            });
+            let vendor_id = data
+                .get(VENDOR_ID_OFFSET..VENDOR_ID_OFFSET + 4)
+                .map(|id| id.iter().map(|&c| char::from(c)).collect::<String>())
+                .unwrap_or_default()
+                // Some fonts pad the ID with NUL instead of spaces.
+                .trim_end_matches([' ', '\0'])
+                .to_string();
+            let vendor = Vendor::find(&vendor_id);
+            let fs_selection = read_u16(SELECTION_OFFSET);
+            let first_char_index = read_u16(FIRST_CHAR_INDEX_OFFSET);
+            let last_char_index = read_u16(LAST_CHAR_INDEX_OFFSET);
             let style = table.style();
             let is_bold = table.is_bold();
             let use_typographic_metrics = table.use_typographic_metrics();
@@ -203,15 +791,40 @@ impl Table {
             let windows_descender = table.windows_descender();
             let x_height = table.x_height();
             let capital_height = table.capital_height();
+            let default_char = (table.version >= 2).then(|| read_u16(DEFAULT_CHAR_OFFSET));
+            let break_char = (table.version >= 2).then(|| read_u16(BREAK_CHAR_OFFSET));
+            let max_context = (table.version >= 2).then(|| read_u16(MAX_CONTEXT_OFFSET));
+            // Sizes are stored in TWIPs, 1/20 of a point.
+            let optical_point_size_range = (table.version >= 5).then(|| OpticalPointSizeRange {
+                lower: f64::from(read_u16(LOWER_OPTICAL_POINT_SIZE_OFFSET)) / 20.0,
+                upper: f64::from(read_u16(UPPER_OPTICAL_POINT_SIZE_OFFSET)) / 20.0,
+            });
             Some(Self {
+                version: u16::from(table.version),
+                x_avg_char_width,
                 weight: weight.into(),
+                weight_class: weight.to_number(),
+                css_font_weight: weight.to_number().clamp(1, 1000).to_string(),
                 width: width.into(),
+                width_class,
+                width_percent: Width::from(width).to_percent(),
+                css_font_stretch: Width::from(width).to_css().to_string(),
                 permissions,
+                fs_type: fs_type.into(),
                 is_subsetting_allowed,
                 is_bitmap_embedding_allowed,
                 subscript_metrics: subscript_metrics.into(),
                 superscript_metrics: superscript_metrics.into(),
                 strikeout_metrics: strikeout_metrics.into(),
+                family_class,
+                panose: panose.into(),
+                unicode_ranges,
+                code_page_ranges,
+                vendor_id,
+                vendor,
+                fs_selection: fs_selection.into(),
+                first_char_index,
+                last_char_index,
                 style: style.into(),
                 is_bold,
                 use_typographic_metrics,
@@ -222,6 +835,10 @@ impl Table {
                 windows_descender,
                 x_height,
                 capital_height,
+                default_char,
+                break_char,
+                max_context,
+                optical_point_size_range,
             })
         } else {
             None
@@ -0,0 +1,460 @@
+use serde::Serialize;
+#[cfg(feature = "wasm")]
+use tsify::Tsify;
+
+const WEIGHTS: &[&str] = &[
+    "Very Light",
+    "Light",
+    "Thin",
+    "Book",
+    "Medium",
+    "Demi",
+    "Bold",
+    "Heavy",
+    "Black",
+    "Extra Black",
+];
+
+const CONTRASTS: &[&str] = &[
+    "None",
+    "Very Low",
+    "Low",
+    "Medium Low",
+    "Medium",
+    "Medium High",
+    "High",
+    "Very High",
+    "Horizontal Low",
+    "Horizontal Medium",
+    "Horizontal High",
+    "Broken",
+];
+
+const SERIF_STYLES: &[&str] = &[
+    "Cove",
+    "Obtuse Cove",
+    "Square Cove",
+    "Obtuse Square Cove",
+    "Square",
+    "Thin",
+    "Oval",
+    "Exaggerated",
+    "Triangle",
+    "Normal Sans",
+    "Obtuse Sans",
+    "Perpendicular Sans",
+    "Flared",
+    "Rounded",
+    "Script",
+];
+
+const SPACINGS: &[&str] = &["Proportional Spaced", "Monospaced"];
+
+const SYMBOL_ASPECT_RATIOS: &[&str] = &[
+    "No Width",
+    "Exceptionally Wide",
+    "Super Wide",
+    "Very Wide",
+    "Wide",
+    "Normal",
+    "Narrow",
+    "Very Narrow",
+];
+
+// https://monotype.github.io/panose/pan2.htm
+/// Names of the digits after the family kind along with the meanings of
+/// their values, starting from 2. Values 0 and 1 always mean "Any" and "No Fit".
+type Digits = [(&'static str, &'static [&'static str]); 9];
+
+const LATIN_TEXT: Digits = [
+    // "Script" is used only by decorative fonts.
+    ("Serif Style", SERIF_STYLES.split_at(14).0),
+    ("Weight", WEIGHTS),
+    (
+        "Proportion",
+        &[
+            "Old Style",
+            "Modern",
+            "Even Width",
+            "Extended",
+            "Condensed",
+            "Very Extended",
+            "Very Condensed",
+            "Monospaced",
+        ],
+    ),
+    ("Contrast", CONTRASTS),
+    (
+        "Stroke Variation",
+        &[
+            "No Variation",
+            "Gradual/Diagonal",
+            "Gradual/Transitional",
+            "Gradual/Vertical",
+            "Gradual/Horizontal",
+            "Rapid/Vertical",
+            "Rapid/Horizontal",
+            "Instant/Vertical",
+            "Instant/Horizontal",
+        ],
+    ),
+    (
+        "Arm Style",
+        &[
+            "Straight Arms/Horizontal",
+            "Straight Arms/Wedge",
+            "Straight Arms/Vertical",
+            "Straight Arms/Single Serif",
+            "Straight Arms/Double Serif",
+            "Non-Straight/Horizontal",
+            "Non-Straight/Wedge",
+            "Non-Straight/Vertical",
+            "Non-Straight/Single Serif",
+            "Non-Straight/Double Serif",
+        ],
+    ),
+    (
+        "Letterform",
+        &[
+            "Normal/Contact",
+            "Normal/Weighted",
+            "Normal/Boxed",
+            "Normal/Flattened",
+            "Normal/Rounded",
+            "Normal/Off Center",
+            "Normal/Square",
+            "Oblique/Contact",
+            "Oblique/Weighted",
+            "Oblique/Boxed",
+            "Oblique/Flattened",
+            "Oblique/Rounded",
+            "Oblique/Off Center",
+            "Oblique/Square",
+        ],
+    ),
+    (
+        "Midline",
+        &[
+            "Standard/Trimmed",
+            "Standard/Pointed",
+            "Standard/Serifed",
+            "High/Trimmed",
+            "High/Pointed",
+            "High/Serifed",
+            "Constant/Trimmed",
+            "Constant/Pointed",
+            "Constant/Serifed",
+            "Low/Trimmed",
+            "Low/Pointed",
+            "Low/Serifed",
+        ],
+    ),
+    (
+        "X-height",
+        &[
+            "Constant/Small",
+            "Constant/Standard",
+            "Constant/Large",
+            "Ducking/Small",
+            "Ducking/Standard",
+            "Ducking/Large",
+        ],
+    ),
+];
+
+const LATIN_HAND_WRITTEN: Digits = [
+    (
+        "Tool Kind",
+        &[
+            "Flat Nib",
+            "Pressure Point",
+            "Engraved",
+            "Ball (Round Cap)",
+            "Brush",
+            "Rough",
+            "Felt Pen/Brush Tip",
+            "Wild Brush - Drips a lot",
+        ],
+    ),
+    ("Weight", WEIGHTS),
+    ("Spacing", SPACINGS),
+    (
+        "Aspect Ratio",
+        &[
+            "Very Condensed",
+            "Condensed",
+            "Normal",
+            "Expanded",
+            "Very Expanded",
+        ],
+    ),
+    ("Contrast", CONTRASTS.split_at(8).0),
+    (
+        "Topology",
+        &[
+            "Roman Disconnected",
+            "Roman Trailing",
+            "Roman Connected",
+            "Cursive Disconnected",
+            "Cursive Trailing",
+            "Cursive Connected",
+            "Blackletter Disconnected",
+            "Blackletter Trailing",
+            "Blackletter Connected",
+        ],
+    ),
+    (
+        "Form",
+        &[
+            "Upright/No Wrapping",
+            "Upright/Some Wrapping",
+            "Upright/More Wrapping",
+            "Upright/Extreme Wrapping",
+            "Oblique/No Wrapping",
+            "Oblique/Some Wrapping",
+            "Oblique/More Wrapping",
+            "Oblique/Extreme Wrapping",
+            "Exaggerated/No Wrapping",
+            "Exaggerated/Some Wrapping",
+            "Exaggerated/More Wrapping",
+            "Exaggerated/Extreme Wrapping",
+        ],
+    ),
+    (
+        "Finials",
+        &[
+            "None/No loops",
+            "None/Closed loops",
+            "None/Open loops",
+            "Sharp/No loops",
+            "Sharp/Closed loops",
+            "Sharp/Open loops",
+            "Tapered/No loops",
+            "Tapered/Closed loops",
+            "Tapered/Open loops",
+            "Round/No loops",
+            "Round/Closed loops",
+            "Round/Open loops",
+        ],
+    ),
+    (
+        "X-ascent",
+        &["Very Low", "Low", "Medium", "High", "Very High"],
+    ),
+];
+
+const LATIN_DECORATIVE: Digits = [
+    (
+        "Class",
+        &[
+            "Derivative",
+            "Non-standard Topology",
+            "Non-standard Elements",
+            "Non-standard Aspect",
+            "Initials",
+            "Cartoon",
+            "Picture Stems",
+            "Ornamented",
+            "Text and Background",
+            "Collage",
+            "Montage",
+        ],
+    ),
+    ("Weight", WEIGHTS),
+    (
+        "Aspect",
+        &[
+            "Super Condensed",
+            "Very Condensed",
+            "Condensed",
+            "Normal",
+            "Extended",
+            "Very Extended",
+            "Super Extended",
+            "Monospaced",
+        ],
+    ),
+    ("Contrast", CONTRASTS),
+    ("Serif Variant", SERIF_STYLES),
+    (
+        "Treatment",
+        &[
+            "None - Standard Solid Fill",
+            "White/No Fill",
+            "Patterned Fill",
+            "Complex Fill",
+            "Shaped Fill",
+            "Drawn/Distressed",
+        ],
+    ),
+    (
+        "Lining",
+        &[
+            "None",
+            "Inline",
+            "Outline",
+            "Engraved (Multiple Lines)",
+            "Shadow",
+            "Relief",
+            "Backdrop",
+        ],
+    ),
+    (
+        "Topology",
+        &[
+            "Standard",
+            "Square",
+            "Multiple Segment",
+            "Deco (E,M,S) Waco midlines",
+            "Uneven Weighting",
+            "Diverse Arms",
+            "Diverse Forms",
+            "Lombardic Forms",
+            "Upper Case in Lower Case",
+            "Implied Topology",
+            "Horseshoe E and A",
+            "Cursive",
+            "Blackletter",
+            "Swash Variance",
+        ],
+    ),
+    (
+        "Range of Characters",
+        &[
+            "Extended Collection",
+            "Litterals",
+            "No Lower Case",
+            "Small Caps",
+        ],
+    ),
+];
+
+const LATIN_SYMBOL: Digits = [
+    (
+        "Kind",
+        &[
+            "Montages",
+            "Pictures",
+            "Shapes",
+            "Scientific",
+            "Music",
+            "Expert",
+            "Patterns",
+            "Boarders",
+            "Icons",
+            "Logos",
+            "Industry specific",
+        ],
+    ),
+    ("Weight", WEIGHTS),
+    ("Spacing", SPACINGS),
+    ("Aspect Ratio and Contrast", &["No Contrast"]),
+    ("Aspect Ratio of Character 94", SYMBOL_ASPECT_RATIOS),
+    ("Aspect Ratio of Character 119", SYMBOL_ASPECT_RATIOS),
+    ("Aspect Ratio of Character 157", SYMBOL_ASPECT_RATIOS),
+    ("Aspect Ratio of Character 163", SYMBOL_ASPECT_RATIOS),
+    ("Aspect Ratio of Character 211", SYMBOL_ASPECT_RATIOS),
+];
+
+/// A PANOSE family kind. Defines how the rest of the digits are interpreted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi))]
+pub enum FamilyKind {
+    Any,
+    NoFit,
+    LatinText,
+    LatinHandWritten,
+    LatinDecorative,
+    LatinSymbol,
+    /// A family kind that is not defined for OpenType fonts.
+    Unknown,
+}
+
+impl From<u8> for FamilyKind {
+    fn from(value: u8) -> FamilyKind {
+        match value {
+            0 => FamilyKind::Any,
+            1 => FamilyKind::NoFit,
+            2 => FamilyKind::LatinText,
+            3 => FamilyKind::LatinHandWritten,
+            4 => FamilyKind::LatinDecorative,
+            5 => FamilyKind::LatinSymbol,
+            _ => FamilyKind::Unknown,
+        }
+    }
+}
+
+/// A decoded PANOSE digit.
+#[derive(Clone, Debug, Serialize)]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi))]
+pub struct PanoseDigit {
+    /// A name of the digit for the family kind, e.g. "Serif Style".
+    ///
+    /// `None` when the family kind is not a Latin one.
+    pub name: Option<String>,
+
+    /// A raw value.
+    pub value: u8,
+
+    /// A meaning of the value, e.g. "Cove".
+    ///
+    /// `None` when the value is not defined for the digit.
+    pub meaning: Option<String>,
+}
+
+/// A [PANOSE](https://docs.microsoft.com/en-us/typography/opentype/spec/os2#panose)
+/// classification.
+#[derive(Clone, Debug, Serialize)]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi))]
+#[serde(rename_all = "camelCase")]
+pub struct Panose {
+    /// Raw 10 bytes.
+    pub bytes: [u8; 10],
+
+    /// A family kind.
+    pub family_kind: FamilyKind,
+
+    /// The rest of the 9 digits decoded according to the family kind.
+    pub digits: Vec<PanoseDigit>,
+}
+
+impl From<[u8; 10]> for Panose {
+    fn from(bytes: [u8; 10]) -> Panose {
+        let family_kind = FamilyKind::from(bytes[0]);
+        let definitions = match family_kind {
+            FamilyKind::LatinText => Some(&LATIN_TEXT),
+            FamilyKind::LatinHandWritten => Some(&LATIN_HAND_WRITTEN),
+            FamilyKind::LatinDecorative => Some(&LATIN_DECORATIVE),
+            FamilyKind::LatinSymbol => Some(&LATIN_SYMBOL),
+            _ => None,
+        };
+
+        let digits = bytes[1..]
+            .iter()
+            .enumerate()
+            .map(|(i, &value)| {
+                let (name, meanings) = match definitions {
+                    Some(definitions) => (Some(definitions[i].0), definitions[i].1),
+                    None => (None, [].as_slice()),
+                };
+                let meaning = match value {
+                    0 => Some("Any"),
+                    1 => Some("No Fit"),
+                    _ => meanings.get(usize::from(value) - 2).copied(),
+                };
+
+                PanoseDigit {
+                    name: name.map(str::to_string),
+                    value,
+                    meaning: meaning.map(str::to_string),
+                }
+            })
+            .collect();
+
+        Panose {
+            bytes,
+            family_kind,
+            digits,
+        }
+    }
+}
@@ -0,0 +1,391 @@
+use crate::parser::Stream;
+use serde::Serialize;
+#[cfg(feature = "wasm")]
+use tsify::Tsify;
+use ttf_parser::post;
+
+// https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6post.html
+/// A list of Macintosh glyph names.
+const MACINTOSH_NAMES: &[&str] = &[
+    ".notdef",
+    ".null",
+    "nonmarkingreturn",
+    "space",
+    "exclam",
+    "quotedbl",
+    "numbersign",
+    "dollar",
+    "percent",
+    "ampersand",
+    "quotesingle",
+    "parenleft",
+    "parenright",
+    "asterisk",
+    "plus",
+    "comma",
+    "hyphen",
+    "period",
+    "slash",
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "colon",
+    "semicolon",
+    "less",
+    "equal",
+    "greater",
+    "question",
+    "at",
+    "A",
+    "B",
+    "C",
+    "D",
+    "E",
+    "F",
+    "G",
+    "H",
+    "I",
+    "J",
+    "K",
+    "L",
+    "M",
+    "N",
+    "O",
+    "P",
+    "Q",
+    "R",
+    "S",
+    "T",
+    "U",
+    "V",
+    "W",
+    "X",
+    "Y",
+    "Z",
+    "bracketleft",
+    "backslash",
+    "bracketright",
+    "asciicircum",
+    "underscore",
+    "grave",
+    "a",
+    "b",
+    "c",
+    "d",
+    "e",
+    "f",
+    "g",
+    "h",
+    "i",
+    "j",
+    "k",
+    "l",
+    "m",
+    "n",
+    "o",
+    "p",
+    "q",
+    "r",
+    "s",
+    "t",
+    "u",
+    "v",
+    "w",
+    "x",
+    "y",
+    "z",
+    "braceleft",
+    "bar",
+    "braceright",
+    "asciitilde",
+    "Adieresis",
+    "Aring",
+    "Ccedilla",
+    "Eacute",
+    "Ntilde",
+    "Odieresis",
+    "Udieresis",
+    "aacute",
+    "agrave",
+    "acircumflex",
+    "adieresis",
+    "atilde",
+    "aring",
+    "ccedilla",
+    "eacute",
+    "egrave",
+    "ecircumflex",
+    "edieresis",
+    "iacute",
+    "igrave",
+    "icircumflex",
+    "idieresis",
+    "ntilde",
+    "oacute",
+    "ograve",
+    "ocircumflex",
+    "odieresis",
+    "otilde",
+    "uacute",
+    "ugrave",
+    "ucircumflex",
+    "udieresis",
+    "dagger",
+    "degree",
+    "cent",
+    "sterling",
+    "section",
+    "bullet",
+    "paragraph",
+    "germandbls",
+    "registered",
+    "copyright",
+    "trademark",
+    "acute",
+    "dieresis",
+    "notequal",
+    "AE",
+    "Oslash",
+    "infinity",
+    "plusminus",
+    "lessequal",
+    "greaterequal",
+    "yen",
+    "mu",
+    "partialdiff",
+    "summation",
+    "product",
+    "pi",
+    "integral",
+    "ordfeminine",
+    "ordmasculine",
+    "Omega",
+    "ae",
+    "oslash",
+    "questiondown",
+    "exclamdown",
+    "logicalnot",
+    "radical",
+    "florin",
+    "approxequal",
+    "Delta",
+    "guillemotleft",
+    "guillemotright",
+    "ellipsis",
+    "nonbreakingspace",
+    "Agrave",
+    "Atilde",
+    "Otilde",
+    "OE",
+    "oe",
+    "endash",
+    "emdash",
+    "quotedblleft",
+    "quotedblright",
+    "quoteleft",
+    "quoteright",
+    "divide",
+    "lozenge",
+    "ydieresis",
+    "Ydieresis",
+    "fraction",
+    "currency",
+    "guilsinglleft",
+    "guilsinglright",
+    "fi",
+    "fl",
+    "daggerdbl",
+    "periodcentered",
+    "quotesinglbase",
+    "quotedblbase",
+    "perthousand",
+    "Acircumflex",
+    "Ecircumflex",
+    "Aacute",
+    "Edieresis",
+    "Egrave",
+    "Iacute",
+    "Icircumflex",
+    "Idieresis",
+    "Igrave",
+    "Oacute",
+    "Ocircumflex",
+    "apple",
+    "Ograve",
+    "Uacute",
+    "Ucircumflex",
+    "Ugrave",
+    "dotlessi",
+    "circumflex",
+    "tilde",
+    "macron",
+    "breve",
+    "dotaccent",
+    "ring",
+    "cedilla",
+    "hungarumlaut",
+    "ogonek",
+    "caron",
+    "Lslash",
+    "lslash",
+    "Scaron",
+    "scaron",
+    "Zcaron",
+    "zcaron",
+    "brokenbar",
+    "Eth",
+    "eth",
+    "Yacute",
+    "yacute",
+    "Thorn",
+    "thorn",
+    "minus",
+    "multiply",
+    "onesuperior",
+    "twosuperior",
+    "threesuperior",
+    "onehalf",
+    "onequarter",
+    "threequarters",
+    "franc",
+    "Gbreve",
+    "gbreve",
+    "Idotaccent",
+    "Scedilla",
+    "scedilla",
+    "Cacute",
+    "cacute",
+    "Ccaron",
+    "ccaron",
+    "dcroat",
+];
+
+/// A [PostScript Table](https://docs.microsoft.com/en-us/typography/opentype/spec/post).
+#[derive(Clone, Debug, Serialize)]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi))]
+#[serde(rename = "POSTTable", rename_all = "camelCase")]
+pub struct Table {
+    /// Table version. One of 1.0, 2.0, 2.5, 3.0 or 4.0.
+    pub version: f64,
+    /// Italic angle in counter-clockwise degrees from the vertical.
+    pub italic_angle: f64,
+    /// Suggested distance of the top of the underline from the baseline.
+    pub underline_position: i16,
+    /// Suggested values for the underline thickness.
+    pub underline_thickness: i16,
+    /// Checks that the font is monospaced.
+    pub is_fixed_pitch: bool,
+    /// Minimum memory usage when an OpenType font is downloaded.
+    pub min_mem_type42: u32,
+    /// Maximum memory usage when an OpenType font is downloaded.
+    pub max_mem_type42: u32,
+    /// Minimum memory usage when an OpenType font is downloaded as a Type 1 font.
+    pub min_mem_type1: u32,
+    /// Maximum memory usage when an OpenType font is downloaded as a Type 1 font.
+    pub max_mem_type1: u32,
+    /// Glyph names indexed by glyph ID.
+    ///
+    /// Empty for versions 3.0 and 4.0, which do not store glyph names.
+    /// Names that cannot be resolved are empty strings.
+    pub glyph_names: Vec<String>,
+}
+
+impl Table {
+    /// Parses the table from a raw data.
+    ///
+    /// `table` is the one parsed by `ttf-parser`, which is `None` when the
+    /// table is missing or malformed.
+    pub fn new(table: Option<post::Table>, data: &[u8], number_of_glyphs: u16) -> Option<Self> {
+        table?;
+
+        let mut s = Stream::new(data);
+        let version = s.read_version()?;
+        let italic_angle = s.read_fixed()?;
+        let underline_position = s.read::<i16>()?;
+        let underline_thickness = s.read::<i16>()?;
+        let is_fixed_pitch = s.read::<u32>()? != 0;
+        let min_mem_type42 = s.read::<u32>()?;
+        let max_mem_type42 = s.read::<u32>()?;
+        let min_mem_type1 = s.read::<u32>()?;
+        let max_mem_type1 = s.read::<u32>()?;
+
+        let glyph_names = match version {
+            1.0 => MACINTOSH_NAMES
+                .iter()
+                .take(usize::from(number_of_glyphs))
+                .map(|name| name.to_string())
+                .collect(),
+            2.0 => parse_format2_names(s).unwrap_or_default(),
+            2.5 => parse_format25_names(s).unwrap_or_default(),
+            _ => Vec::new(),
+        };
+
+        Some(Self {
+            version,
+            italic_angle,
+            underline_position,
+            underline_thickness,
+            is_fixed_pitch,
+            min_mem_type42,
+            max_mem_type42,
+            min_mem_type1,
+            max_mem_type1,
+            glyph_names,
+        })
+    }
+}
+
+fn parse_format2_names(mut s: Stream) -> Option<Vec<String>> {
+    let number_of_glyphs = s.read::<u16>()?;
+    let mut indexes = Vec::with_capacity(usize::from(number_of_glyphs));
+    for _ in 0..number_of_glyphs {
+        indexes.push(usize::from(s.read::<u16>()?));
+    }
+
+    // Glyph names are stored as Pascal strings.
+    let mut custom_names = Vec::new();
+    while let Some(len) = s.read::<u8>() {
+        let Some(name) = s.read_bytes(usize::from(len)) else {
+            break;
+        };
+        custom_names.push(String::from_utf8_lossy(name).into_owned());
+    }
+
+    let names = indexes
+        .into_iter()
+        .map(|index| match MACINTOSH_NAMES.get(index) {
+            Some(name) => name.to_string(),
+            None => custom_names
+                .get(index - MACINTOSH_NAMES.len())
+                .cloned()
+                .unwrap_or_default(),
+        })
+        .collect();
+
+    Some(names)
+}
+
+fn parse_format25_names(mut s: Stream) -> Option<Vec<String>> {
+    let count = s.read::<u16>()?;
+    let mut names = Vec::with_capacity(usize::from(count));
+    for glyph_id in 0..count {
+        // An offset from the glyph ID to its index in the Macintosh order.
+        let offset = s.read::<i8>()?;
+        let index = i32::from(glyph_id) + i32::from(offset);
+        let name = usize::try_from(index)
+            .ok()
+            .and_then(|index| MACINTOSH_NAMES.get(index))
+            .copied()
+            .unwrap_or_default();
+        names.push(name.to_string());
+    }
+
+    Some(names)
+}
@@ -0,0 +1,223 @@
+use serde::Serialize;
+#[cfg(feature = "wasm")]
+use tsify::Tsify;
+
+// https://docs.microsoft.com/en-us/typography/vendors/
+/// Registered vendors sorted by ID. IDs shorter than 4 characters are padded
+/// with spaces, as in the `achVendID` field.
+///
+/// A snapshot of the registry. Websites are listed only for active foundries.
+const VENDORS: &[(&str, &str, Option<&str>)] = &[
+    ("2REB", "2Rebels", None),
+    ("39BC", "Finley's Barcode Fonts", None),
+    ("3ip ", "Three Islands Press", None),
+    ("4FEB", "4th February", None),
+    ("ABC ", "Altek Instruments", None),
+    ("ABOU", "Aboutype, Inc.", None),
+    ("ACUT", "Acute Type", None),
+    ("ADBE", "Adobe", Some("https://www.adobe.com/type/")),
+    ("ADBO", "Adobe", Some("https://www.adobe.com/type/")),
+    ("ADG ", "Apply Design Group", None),
+    ("AGFA", "Agfa Monotype Corporation", None),
+    ("ALTS", "Altsys / Macromedia", None),
+    ("APOS", "Apostrophic Laboratory", None),
+    ("APPL", "Apple", Some("https://developer.apple.com/fonts/")),
+    (
+        "ARPH",
+        "Arphic Technology Co.",
+        Some("https://www.arphic.com/"),
+    ),
+    ("ARS ", "EN ARS Ltd.", None),
+    ("AZLS", "Azalea Software, Inc.", None),
+    ("B&H ", "Bigelow & Holmes", None),
+    ("BARS", "CIA (BAR CODES) UK", None),
+    ("BITM", "Bitmap Software", None),
+    ("BITS", "Bitstream Inc.", None),
+    ("BLAH", "Mister Bla's Fontworx", None),
+    ("BWFW", "B/W Fontworks", None),
+    ("C&C ", "Carter & Cone", None),
+    ("CASL", "H.W. Caslon & Company Ltd", None),
+    ("CFA ", "Computer Fonts Australia", None),
+    ("CONR", "Connare.com", None),
+    ("CTDL", "China Type Designs Ltd.", None),
+    (
+        "DAMA",
+        "Dalton Maag Limited",
+        Some("https://www.daltonmaag.com/"),
+    ),
+    ("DS  ", "Dainippon Screen Mfg. Co., Ltd.", None),
+    ("DSCI", "Design Science Inc.", None),
+    ("DTC ", "Digital Typeface Corp.", None),
+    ("DTPS", "DTP-Software", None),
+    ("DUXB", "Duxbury Systems, Inc.", None),
+    ("DYNA", "DynaLab Inc.", None),
+    ("EDGE", "Rivers Edge Corp.", None),
+    ("EFF ", "Elsner+Flake", None),
+    ("EFNT", "E Fonts L.L.C.", None),
+    ("ELSE", "Elseware Corporation", None),
+    ("EMGR", "Emigre", Some("https://www.emigre.com/")),
+    ("EPSN", "Epson", None),
+    ("ESIG", "E-Signature", None),
+    ("FBI ", "The Font Bureau, Inc.", None),
+    ("FCAB", "The Font Cabinet", None),
+    ("FDI ", "FDI fonts.info", None),
+    ("FJ  ", "Fujitsu", None),
+    ("FONT", "Font Source", None),
+    ("FS  ", "Formula Solutions", None),
+    ("FSE ", "Font Source Europe", None),
+    ("FSI ", "FSI Fonts und Software GmbH", None),
+    ("FWRE", "Fontware Limited", None),
+    ("GALA", "Galapagos Design Group, Inc.", None),
+    ("GD  ", "GD Fonts", None),
+    ("GLYF", "Glyph Systems", None),
+    ("GOOG", "Google", Some("https://fonts.google.com/")),
+    ("GPI ", "Gamma Productions, Inc.", None),
+    ("H&FJ", "Hoefler & Co.", Some("https://www.typography.com/")),
+    ("HA  ", "HanYang System", None),
+    ("HILL", "Hill Systems", None),
+    ("HL  ", "High-Logic", Some("https://www.high-logic.com/")),
+    ("HOUS", "House Industries", Some("https://houseind.com/")),
+    ("HP  ", "Hewlett-Packard", None),
+    ("HY  ", "HanYang Information & Communication", None),
+    ("IBM ", "IBM", Some("https://www.ibm.com/plex/")),
+    ("IDEE", "IDEE TYPOGRAFICA", None),
+    ("IDF ", "International Digital Fonts", None),
+    ("ILP ", "Indigenous Language Project", None),
+    ("IMPR", "Impress", None),
+    ("ITC ", "ITC (International Typeface Corporation)", None),
+    ("JPTT", "Jeremy Tankard Typography", None),
+    ("KATF", "Kingsley/ATF", None),
+    ("KLIM", "Klim Type Foundry", Some("https://klim.co.nz/")),
+    ("KLTF", "Karsten Luecke", None),
+    ("LANS", "Lanston Type Company", None),
+    ("LARA", "Larabie Fonts", None),
+    ("LETR", "Letraset", None),
+    ("LGX ", "Logix Research Institute", None),
+    ("LING", "Linguist's Software", None),
+    ("LINO", "Linotype GmbH", Some("https://www.linotype.com/")),
+    ("LP  ", "LetterPerfect Fonts", None),
+    ("LTRX", "Lighttracks", None),
+    ("MACR", "Macromedia", None),
+    ("MC  ", "Cerajewski Computer Consulting", None),
+    ("MILL", "Millan", None),
+    ("MJ  ", "Majus Corporation", None),
+    ("MLGC", "Micrologic Software", None),
+    (
+        "MONO",
+        "Monotype Imaging Inc.",
+        Some("https://www.monotype.com/"),
+    ),
+    (
+        "MS  ",
+        "Microsoft Corp.",
+        Some("https://www.microsoft.com/typography/"),
+    ),
+    ("MSCR", "Majus Corporation", None),
+    (
+        "MT  ",
+        "Monotype Typography",
+        Some("https://www.monotype.com/"),
+    ),
+    ("MTY ", "Motoya Co., LTD.", None),
+    ("NB  ", "No Bodoni Typography", None),
+    ("NDTC", "Neufville Digital", None),
+    ("NEC ", "NEC Corporation", None),
+    ("NIS ", "NIS Corporation", None),
+    ("ORBI", "Orbit Enterprises, Inc.", None),
+    ("P22 ", "P22 Inc.", Some("https://p22.com/")),
+    ("PARA", "ParaType Inc.", Some("https://www.paratype.com/")),
+    ("PDWX", "Parsons Design Workx", None),
+    ("PF  ", "Phil's Fonts, Inc.", None),
+    ("PRFS", "Production First Software", None),
+    ("PYRS", "FontLab Ltd.", Some("https://www.fontlab.com/")),
+    ("PfEd", "FontForge", Some("https://fontforge.org/")),
+    ("QMSI", "QMS/Imagen", None),
+    ("RKFN", "R K Fonts", None),
+    ("RSMS", "Rasmus Andersson", Some("https://rsms.me/")),
+    ("RUDY", "RudynFluffy", None),
+    ("SAJA", "Sajatypeworks", None),
+    ("SAX ", "s.a.x. Software gmbh", None),
+    ("SFI ", "Software Friends", None),
+    ("SFUN", "Soft Union", None),
+    ("SG  ", "Scooter Graphics", None),
+    ("SIG ", "Signature Software, Inc.", None),
+    (
+        "SIL ",
+        "SIL International",
+        Some("https://software.sil.org/fonts/"),
+    ),
+    ("SKZ ", "Celtic Lady's Fonts", None),
+    ("SOHO", "Soft Horizons", None),
+    ("SOS ", "Standing Ovations Software", None),
+    ("STF ", "Brian Sooy & Co + Sooy Type Foundry", None),
+    ("SUNW", "Sun Microsystems", None),
+    ("SWFT", "Swfte International", None),
+    ("SYN ", "SynFonts", None),
+    ("Sean", "The FontSite", None),
+    ("TDR ", "Tansin A. Darcos & Co.", None),
+    ("TERM", "Terminal Design, Inc.", None),
+    ("TF  ", "Treacyfaces / Headliners", None),
+    ("TILD", "SIA Tilde", None),
+    ("TIRO", "Tiro Typeworks", Some("https://www.tiro.com/")),
+    ("TMF ", "The MicroFoundry", None),
+    ("TPTC", "Test Pilot Collective", None),
+    ("TPTQ", "Typotheque", Some("https://www.typotheque.com/")),
+    ("TR  ", "Type Revivals", None),
+    ("TS  ", "TamilSoft Corporation", None),
+    ("UA  ", "UnAuthorized Type", None),
+    (
+        "URW ",
+        "URW++ Design and Development",
+        Some("https://www.urwtype.com/"),
+    ),
+    ("VKP ", "Vijay K. Patel", None),
+    ("VLKF", "Visualogik Technology & Design", None),
+    ("VOG ", "Martin Vogel", None),
+    ("Y&Y ", "Y&Y, Inc.", None),
+    ("ZEGR", "Zebra Font Factory", None),
+    ("ZSFT", "Zsoft", None),
+    ("robo", "Buro Petr van Blokland", None),
+    ("zeta", "Tangram Studio", None),
+];
+
+/// A font vendor from the [registered vendor list](https://docs.microsoft.com/en-us/typography/vendors/).
+#[derive(Clone, Debug, Serialize)]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi))]
+pub struct Vendor {
+    /// A vendor ID without the space padding.
+    pub id: String,
+
+    /// A vendor name.
+    pub name: String,
+
+    /// A vendor website.
+    pub url: Option<String>,
+}
+
+impl Vendor {
+    /// Looks up a registered vendor by its ID.
+    ///
+    /// IDs are case-sensitive. Trailing spaces and NULs are optional.
+    pub fn find(id: &str) -> Option<Self> {
+        let id = format!("{:<4}", id.trim_end_matches([' ', '\0']));
+        let index = VENDORS.binary_search_by_key(&id.as_str(), |v| v.0).ok()?;
+        let (id, name, url) = VENDORS[index];
+
+        Some(Self {
+            id: id.trim_end().to_string(),
+            name: name.to_string(),
+            url: url.map(str::to_string),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::VENDORS;
+
+    #[test]
+    fn vendors_are_sorted_and_unique() {
+        assert!(VENDORS.iter().all(|v| v.0.len() == 4));
+        assert!(VENDORS.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+}
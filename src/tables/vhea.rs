@@ -0,0 +1,84 @@
+use crate::parser::Stream;
+use serde::Serialize;
+#[cfg(feature = "wasm")]
+use tsify::Tsify;
+use ttf_parser::vhea;
+
+/// A [Vertical Header Table](https://docs.microsoft.com/en-us/typography/opentype/spec/vhea).
+#[derive(Clone, Copy, Debug, Serialize)]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi))]
+#[serde(rename = "VHEATable", rename_all = "camelCase")]
+pub struct Table {
+    /// Table version. Either 1.0 or 1.1.
+    pub version: f64,
+    /// Face ascender.
+    ///
+    /// `vertTypoAscender` in version 1.1.
+    pub ascender: i16,
+    /// Face descender.
+    ///
+    /// `vertTypoDescender` in version 1.1.
+    pub descender: i16,
+    /// Face line gap.
+    ///
+    /// `vertTypoLineGap` in version 1.1.
+    pub line_gap: i16,
+    /// Maximum advance height value in the `vmtx` table.
+    pub advance_height_max: i16,
+    /// Minimum top side bearing value in the `vmtx` table.
+    pub min_top_side_bearing: i16,
+    /// Minimum bottom side bearing value.
+    pub min_bottom_side_bearing: i16,
+    /// Maximum of top side bearing plus glyph height.
+    pub y_max_extent: i16,
+    /// Used to calculate the slope of the cursor (rise/run); 0 for vertical.
+    pub caret_slope_rise: i16,
+    /// 1 for vertical.
+    pub caret_slope_run: i16,
+    /// The amount by which a slanted highlight on a glyph needs to be shifted
+    /// to produce the best appearance. Set to 0 for non-slanted fonts.
+    pub caret_offset: i16,
+    /// 0 for current format.
+    pub metric_data_format: i16,
+    /// Number of metrics in the `vmtx` table.
+    pub number_of_metrics: u16,
+}
+
+impl Table {
+    /// Parses the table from a raw data.
+    ///
+    /// `table` is the one parsed by `ttf-parser`, which is `None` when the
+    /// table is missing or malformed.
+    pub fn new(table: Option<vhea::Table>, data: &[u8]) -> Option<Self> {
+        let table = table?;
+
+        let mut s = Stream::new(data);
+        let version = s.read_version()?;
+        s.advance(6); // ascender, descender and line gap
+        let advance_height_max = s.read::<i16>()?;
+        let min_top_side_bearing = s.read::<i16>()?;
+        let min_bottom_side_bearing = s.read::<i16>()?;
+        let y_max_extent = s.read::<i16>()?;
+        let caret_slope_rise = s.read::<i16>()?;
+        let caret_slope_run = s.read::<i16>()?;
+        let caret_offset = s.read::<i16>()?;
+        s.advance(8); // reserved
+        let metric_data_format = s.read::<i16>()?;
+
+        Some(Self {
+            version,
+            ascender: table.ascender,
+            descender: table.descender,
+            line_gap: table.line_gap,
+            advance_height_max,
+            min_top_side_bearing,
+            min_bottom_side_bearing,
+            y_max_extent,
+            caret_slope_rise,
+            caret_slope_run,
+            caret_offset,
+            metric_data_format,
+            number_of_metrics: table.number_of_metrics,
+        })
+    }
+}
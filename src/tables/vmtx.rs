@@ -0,0 +1,45 @@
+use serde::Serialize;
+#[cfg(feature = "wasm")]
+use tsify::Tsify;
+use ttf_parser::{hmtx, GlyphId};
+
+/// Vertical metrics of a glyph.
+#[derive(Clone, Copy, Debug, Serialize)]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi))]
+#[serde(rename_all = "camelCase")]
+pub struct VerticalMetrics {
+    /// Advance height.
+    pub advance: u16,
+    /// Top side bearing.
+    pub top_side_bearing: i16,
+}
+
+/// A [Vertical Metrics Table](https://docs.microsoft.com/en-us/typography/opentype/spec/vmtx).
+#[derive(Clone, Debug, Serialize)]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi))]
+#[serde(rename = "VMTXTable", rename_all = "camelCase")]
+pub struct Table {
+    /// Metrics indexed by glyph ID.
+    ///
+    /// Glyphs after `vhea.numberOfMetrics` reuse the last advance height.
+    pub metrics: Vec<VerticalMetrics>,
+}
+
+impl Table {
+    /// Converts the table parsed by `ttf-parser`.
+    ///
+    /// Returns `None` when the table is missing or malformed.
+    pub fn new(table: Option<hmtx::Table>, number_of_glyphs: u16) -> Option<Self> {
+        let table = table?;
+
+        let metrics = (0..number_of_glyphs)
+            .map(GlyphId)
+            .map(|glyph_id| VerticalMetrics {
+                advance: table.advance(glyph_id).unwrap_or_default(),
+                top_side_bearing: table.side_bearing(glyph_id).unwrap_or_default(),
+            })
+            .collect();
+
+        Some(Self { metrics })
+    }
+}
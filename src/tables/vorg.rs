@@ -0,0 +1,50 @@
+use serde::Serialize;
+#[cfg(feature = "wasm")]
+use tsify::Tsify;
+use ttf_parser::vorg;
+
+/// A vertical origin of a glyph.
+#[derive(Clone, Copy, Debug, Serialize)]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi))]
+#[serde(rename_all = "camelCase")]
+pub struct VertOriginYMetrics {
+    /// Glyph ID.
+    pub glyph_index: u16,
+    /// Y coordinate of the vertical origin.
+    pub vert_origin_y: i16,
+}
+
+/// A [Vertical Origin Table](https://docs.microsoft.com/en-us/typography/opentype/spec/vorg).
+#[derive(Clone, Debug, Serialize)]
+#[cfg_attr(feature = "wasm", derive(Tsify), tsify(into_wasm_abi))]
+#[serde(rename = "VORGTable", rename_all = "camelCase")]
+pub struct Table {
+    /// Y coordinate of the vertical origin for glyphs that are not listed
+    /// in `vertOriginYMetrics`.
+    pub default_vert_origin_y: i16,
+    /// Vertical origins sorted by glyph ID.
+    pub vert_origin_y_metrics: Vec<VertOriginYMetrics>,
+}
+
+impl Table {
+    /// Converts the table parsed by `ttf-parser`.
+    ///
+    /// Returns `None` when the table is missing or malformed.
+    pub fn new(table: Option<vorg::Table>) -> Option<Self> {
+        let table = table?;
+
+        let vert_origin_y_metrics = table
+            .metrics
+            .into_iter()
+            .map(|metrics| VertOriginYMetrics {
+                glyph_index: metrics.glyph_id.0,
+                vert_origin_y: metrics.y,
+            })
+            .collect();
+
+        Some(Self {
+            default_vert_origin_y: table.default_y,
+            vert_origin_y_metrics,
+        })
+    }
+}
@@ -0,0 +1,643 @@
+use js_sys::{Array, Date, Reflect};
+use tsify::Tsify;
+use wasm_bindgen::prelude::*;
+
+use crate::checksum::ChecksumReport;
+use crate::directory::TableDirectory;
+use crate::tables::{
+    self,
+    name::NameId,
+    os2::{Permissions, Purpose, ScriptMetrics, Style},
+    LineMetrics, Tables,
+};
+use crate::{collection, Font, FontErrorKind};
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = console)]
+    fn log(s: &str);
+
+    #[wasm_bindgen(typescript_type = "FontError[]")]
+    pub type FontErrorList;
+
+    #[wasm_bindgen(typescript_type = "(FaceSummary | FontError)[]")]
+    pub type FaceSummaryList;
+}
+
+/// A font parsing error.
+///
+/// Thrown by the `TTFParser` constructor and also used to report
+/// skipped optional tables as warnings.
+#[wasm_bindgen(inspectable)]
+pub struct FontError(crate::FontError);
+
+#[wasm_bindgen]
+impl FontError {
+    /// The kind of the error.
+    #[wasm_bindgen(getter)]
+    pub fn kind(&self) -> FontErrorKind {
+        self.0.kind
+    }
+
+    /// A tag of the table involved, if any.
+    #[wasm_bindgen(getter)]
+    pub fn table(&self) -> Option<String> {
+        self.0.table.clone()
+    }
+
+    /// A byte offset into the font data, when known.
+    #[wasm_bindgen(getter)]
+    pub fn offset(&self) -> Option<u32> {
+        self.0.offset
+    }
+
+    /// A human readable error message.
+    #[wasm_bindgen(getter)]
+    pub fn message(&self) -> String {
+        self.0.message().to_string()
+    }
+}
+
+impl From<crate::FontError> for FontError {
+    fn from(err: crate::FontError) -> FontError {
+        FontError(err)
+    }
+}
+
+/// A font face parser.
+///
+/// Owns the font data. `ttf-parser` parses the face on construction, while
+/// the decoded models, like tables, names and checksums, are computed when
+/// first accessed.
+#[wasm_bindgen(inspectable)]
+pub struct TTFParser(Font);
+
+#[wasm_bindgen]
+impl TTFParser {
+    /// Creates a new `TTFParser` from a raw data.
+    ///
+    /// `index` indicates the specific font face in a font collection.
+    /// Use `TTFParser.facesInCollection` to get the total number of font faces.
+    /// Defaults to 0 if not set.
+    ///
+    /// Required tables: `head`, `hhea` and `maxp`.
+    ///
+    /// If an optional table has invalid data it will be skipped and reported
+    /// in `warnings`.
+    ///
+    /// Throws a `FontError` when the face cannot be parsed.
+    #[wasm_bindgen(constructor)]
+    pub fn new(data: Vec<u8>, index: Option<usize>) -> Result<TTFParser, FontError> {
+        let index = index.unwrap_or_default() as u32;
+        Ok(Self(Font::new(data, index)?))
+    }
+
+    /// Returns the number of faces in a font or a font collection.
+    ///
+    /// A regular font is treated as a collection with a single face.
+    /// Returns `0` when the data is neither a font nor a font collection.
+    #[wasm_bindgen(js_name = "facesInCollection")]
+    pub fn faces_in_collection(data: &[u8]) -> u32 {
+        collection::faces_in_collection(data)
+    }
+
+    /// Parses every face in a font or a font collection.
+    ///
+    /// Returns a summary with the index, family and subfamily of each face,
+    /// or a `FontError` in place of a face that cannot be parsed.
+    #[wasm_bindgen(js_name = "parseAll")]
+    pub fn parse_all(data: &[u8]) -> FaceSummaryList {
+        collection::parse_all(data)
+            .into_iter()
+            .map(|face| match face {
+                Ok(face) => serde_wasm_bindgen::to_value(&face).unwrap_throw(),
+                Err(err) => JsValue::from(FontError(err)),
+            })
+            .collect::<Array>()
+            .unchecked_into()
+    }
+
+    /// Returns every table record of the face along with the sfnt version.
+    #[wasm_bindgen(js_name = "tableDirectory")]
+    pub fn table_directory(&self) -> TableDirectory {
+        self.0.table_directory().clone()
+    }
+
+    /// Recalculates every table checksum and `head.checkSumAdjustment`.
+    ///
+    /// `ttf-parser` doesn't check them, so a corrupted font can still be parsed.
+    #[wasm_bindgen(js_name = "verifyChecksums")]
+    pub fn verify_checksums(&self) -> ChecksumReport {
+        self.0.verify_checksums().clone()
+    }
+
+    /// Returns a copy of the raw data of a table.
+    ///
+    /// The data is copied on purpose: a view into the WebAssembly memory would
+    /// be silently detached as soon as the memory grows. For a view without
+    /// copying, use the offset and the length from `tableDirectory` to take a
+    /// `subarray` of the data passed to the constructor.
+    ///
+    /// Tags shorter than 4 characters are padded with spaces, so `CFF`
+    /// matches the `CFF ` table.
+    ///
+    /// Returns `undefined` when the table is not present or out of bounds.
+    #[wasm_bindgen(js_name = "rawTable")]
+    pub fn raw_table(&self, tag: &str) -> Option<Vec<u8>> {
+        self.0.raw_table(tag).map(<[u8]>::to_vec)
+    }
+
+    /// Optional tables that have invalid data and were skipped.
+    #[wasm_bindgen(getter)]
+    pub fn warnings(&self) -> FontErrorList {
+        self.0
+            .warnings()
+            .iter()
+            .cloned()
+            .map(|err| JsValue::from(FontError(err)))
+            .collect::<Array>()
+            .unchecked_into()
+    }
+
+    /// Returns face's weight class.
+    ///
+    /// Returns `400` when OS/2 table is not present.
+    #[wasm_bindgen(getter, js_name = "weightClass")]
+    pub fn weight_class(&self) -> u16 {
+        self.0.weight_class()
+    }
+
+    /// Returns face's width class.
+    ///
+    /// Returns `5` when OS/2 table is not present.
+    #[wasm_bindgen(getter, js_name = "widthClass")]
+    pub fn width_class(&self) -> u16 {
+        self.0.width_class()
+    }
+
+    /// Returns face's width as a percentage of the normal width.
+    ///
+    /// Returns `100` when OS/2 table is not present.
+    #[wasm_bindgen(getter, js_name = "widthPercent")]
+    pub fn width_percent(&self) -> f64 {
+        self.0.width_percent()
+    }
+
+    /// Returns CSS `font-weight` value.
+    #[wasm_bindgen(getter, js_name = "cssFontWeight")]
+    pub fn css_font_weight(&self) -> String {
+        self.0.css_font_weight()
+    }
+
+    /// Returns CSS `font-stretch` keyword.
+    #[wasm_bindgen(getter, js_name = "cssFontStretch")]
+    pub fn css_font_stretch(&self) -> String {
+        self.0.css_font_stretch().to_string()
+    }
+
+    /// Checks that face is marked as *Bold*.
+    ///
+    /// Returns `false` when OS/2 table is not present.
+    #[wasm_bindgen(getter, js_name = "isBold")]
+    pub fn is_bold(&self) -> bool {
+        self.0.is_bold()
+    }
+
+    /// Checks that face is marked as *Italic*.
+    ///
+    /// Returns `false` when OS/2 table is not present.
+    #[wasm_bindgen(getter, js_name = "isItalic")]
+    pub fn is_italic(&self) -> bool {
+        self.0.is_italic()
+    }
+
+    /// Checks that face is marked as *Monospaced*.
+    ///
+    /// Returns `false` when `post` table is not present.
+    #[wasm_bindgen(getter, js_name = "isMonospaced")]
+    pub fn is_monospaced(&self) -> bool {
+        self.0.is_monospaced()
+    }
+
+    /// Checks that face is marked as *Oblique*.
+    ///
+    /// Returns `false` when OS/2 table is not present or when its version is < 4.
+    #[wasm_bindgen(getter, js_name = "isOblique")]
+    pub fn is_oblique(&self) -> bool {
+        self.0.is_oblique()
+    }
+
+    /// Checks that face is marked as *Regular*.
+    ///
+    /// Returns `false` when OS/2 table is not present.
+    #[wasm_bindgen(getter, js_name = "isRegular")]
+    pub fn is_regular(&self) -> bool {
+        self.0.is_regular()
+    }
+
+    /// Checks that face is variable.
+    ///
+    /// Simply checks the presence of a `fvar` table.
+    #[wasm_bindgen(getter, js_name = "isVariable")]
+    pub fn is_variable(&self) -> bool {
+        self.0.is_variable()
+    }
+
+    // Read https://github.com/freetype/freetype/blob/49270c17011491227ec7bd3fb73ede4f674aa065/src/sfnt/sfobjs.c#L1279
+    // to learn more about the logic behind the following property.
+    /// Returns a horizontal face ascender.
+    ///
+    /// This property is affected by variation axes.
+    #[wasm_bindgen(getter, js_name = "ascender")]
+    pub fn ascender(&self) -> i16 {
+        self.0.ascender()
+    }
+
+    /// Returns a horizontal face descender.
+    ///
+    /// This property is affected by variation axes.
+    #[wasm_bindgen(getter, js_name = "descender")]
+    pub fn descender(&self) -> i16 {
+        self.0.descender()
+    }
+
+    /// Returns face's height.
+    ///
+    /// This property is affected by variation axes.
+    #[wasm_bindgen(getter, js_name = "height")]
+    pub fn height(&self) -> i16 {
+        self.0.height()
+    }
+
+    /// Returns a horizontal face line gap.
+    ///
+    /// This property is affected by variation axes.
+    #[wasm_bindgen(getter, js_name = "lineGap")]
+    pub fn line_gap(&self) -> i16 {
+        self.0.line_gap()
+    }
+
+    /// Returns a horizontal typographic face ascender.
+    ///
+    /// Prefer `ascender` unless you explicitly want this. This is a more
+    /// low-level alternative.
+    ///
+    /// This property is affected by variation axes.
+    ///
+    /// Returns `None` when OS/2 table is not present.
+    #[wasm_bindgen(getter, js_name = "typographicAscender")]
+    pub fn typographic_ascender(&self) -> Option<i16> {
+        self.0.typographic_ascender()
+    }
+
+    /// Returns a horizontal typographic face ascender.
+    ///
+    /// Prefer `ascender` unless you explicitly want this. This is a more
+    /// low-level alternative.
+    ///
+    /// This property is affected by variation axes.
+    ///
+    /// Returns `None` when OS/2 table is not present.
+    #[wasm_bindgen(getter, js_name = "typographicDescender")]
+    pub fn typographic_descender(&self) -> Option<i16> {
+        self.0.typographic_descender()
+    }
+
+    /// Returns a horizontal typographic face line gap.
+    ///
+    /// Prefer `line_gap` unless you explicitly want this. This is a more
+    /// low-level alternative.
+    ///
+    /// This property is affected by variation axes.
+    ///
+    /// Returns `None` when OS/2 table is not present.
+    #[wasm_bindgen(getter, js_name = "typographicLineGap")]
+    pub fn typographic_line_gap(&self) -> Option<i16> {
+        self.0.typographic_line_gap()
+    }
+
+    /// Returns a vertical face ascender.
+    ///
+    /// This property is affected by variation axes.
+    #[wasm_bindgen(getter, js_name = "verticalAscender")]
+    pub fn vertical_ascender(&self) -> Option<i16> {
+        self.0.vertical_ascender()
+    }
+
+    /// Returns a vertical face descender.
+    ///
+    /// This property is affected by variation axes.
+    #[wasm_bindgen(getter, js_name = "verticalDescender")]
+    pub fn vertical_descender(&self) -> Option<i16> {
+        self.0.vertical_descender()
+    }
+
+    /// Returns a vertical face height.
+    ///
+    /// This method is affected by variation axes.
+    #[wasm_bindgen(getter, js_name = "verticalHeight")]
+    pub fn vertical_height(&self) -> Option<i16> {
+        self.0.vertical_height()
+    }
+
+    /// Returns a vertical face line gap.
+    ///
+    /// This property is affected by variation axes.
+    #[wasm_bindgen(getter, js_name = "verticalLineGap")]
+    pub fn vertical_line_gap(&self) -> Option<i16> {
+        self.0.vertical_line_gap()
+    }
+
+    /// Returns face's units per EM.
+    ///
+    /// Guarantee to be in a 16..=16384 range.
+    #[wasm_bindgen(getter, js_name = "unitsPerEm")]
+    pub fn units_per_em(&self) -> u16 {
+        self.0.units_per_em()
+    }
+
+    /// Returns face's x height.
+    ///
+    /// This property is affected by variation axes.
+    ///
+    /// Returns `undefined` when OS/2 table is not present or when its version is < 2.
+    #[wasm_bindgen(getter, js_name = "xHeight")]
+    pub fn x_height(&self) -> Option<i16> {
+        self.0.x_height()
+    }
+
+    /// Returns face's capital height.
+    ///
+    /// This property is affected by variation axes.
+    ///
+    /// Returns `undefined` when OS/2 table is not present or when its version is < 2.
+    #[wasm_bindgen(getter, js_name = "capitalHeight")]
+    pub fn capital_height(&self) -> Option<i16> {
+        self.0.capital_height()
+    }
+
+    /// Returns face's italic angle.
+    ///
+    /// Returns `undefined` when `post` table is not present.
+    #[wasm_bindgen(getter, js_name = "italicAngle")]
+    pub fn italic_angle(&self) -> Option<f32> {
+        self.0.italic_angle()
+    }
+
+    /// Returns face permissions.
+    #[wasm_bindgen(getter)]
+    pub fn permissions(&self) -> Option<Permissions> {
+        self.0.permissions()
+    }
+
+    /// Checks that the face embedding permissions allow the specified purpose.
+    ///
+    /// Returns `false` when OS/2 table is not present.
+    #[wasm_bindgen(js_name = "canUse")]
+    pub fn can_use(&self, purpose: Purpose) -> bool {
+        self.0.can_use(purpose)
+    }
+
+    /// Checks if the face subsetting is allowed.
+    #[wasm_bindgen(getter, js_name = "isSubsettingAllowed")]
+    pub fn is_subsetting_allowed(&self) -> bool {
+        self.0.is_subsetting_allowed()
+    }
+
+    /// Checks if the face bitmaps embedding is allowed.
+    #[wasm_bindgen(getter, js_name = "isBitmapEmbeddingAllowed")]
+    pub fn is_bitmap_embedding_allowed(&self) -> bool {
+        self.0.is_bitmap_embedding_allowed()
+    }
+
+    /// Returns a total number of glyphs in the face.
+    ///
+    /// Never zero.
+    #[wasm_bindgen(getter, js_name = "numberOfGlyphs")]
+    pub fn number_of_glyphs(&self) -> u16 {
+        self.0.number_of_glyphs()
+    }
+
+    /// Returns face's superscript metrics.
+    ///
+    /// This property is affected by variation axes.
+    ///
+    /// Returns `undefined` when OS/2 table is not present.
+    #[wasm_bindgen(getter, js_name = "superScriptMetrics")]
+    pub fn superscript_metrics(&self) -> Option<ScriptMetrics> {
+        self.0.superscript_metrics()
+    }
+
+    /// Returns face's subscript metrics.
+    ///
+    /// This property is affected by variation axes.
+    ///
+    /// Returns `undefined` when OS/2 table is not present.
+    #[wasm_bindgen(getter, js_name = "subscriptMetrics")]
+    pub fn subscript_metrics(&self) -> Option<ScriptMetrics> {
+        self.0.subscript_metrics()
+    }
+
+    /// Returns face's underline metrics.
+    ///
+    /// This property is affected by variation axes.
+    ///
+    /// Returns `undefined` when `post` table is not present.
+    #[wasm_bindgen(getter, js_name = "underlineMetrics")]
+    pub fn underline_metrics(&self) -> Option<LineMetrics> {
+        self.0.underline_metrics()
+    }
+
+    /// Returns face's strikeout metrics.
+    ///
+    /// This property is affected by variation axes.
+    ///
+    /// Returns `undefined` when OS/2 table is not present.
+    #[wasm_bindgen(getter, js_name = "strikeoutMetrics")]
+    pub fn strikeout_metrics(&self) -> Option<LineMetrics> {
+        self.0.strikeout_metrics()
+    }
+
+    /// Returns face style.
+    ///
+    /// Returns `Normal` when OS/2 table is not present.
+    #[wasm_bindgen(getter)]
+    pub fn style(&self) -> Style {
+        self.0.style()
+    }
+
+    /// Returns glyph's vertical advance.
+    ///
+    /// This method is affected by variation axes.
+    ///
+    /// Returns `undefined` when `vmtx` table is not present or the glyph is out of bounds.
+    #[wasm_bindgen(js_name = "glyphVerAdvance")]
+    pub fn glyph_ver_advance(&self, glyph_id: u16) -> Option<u16> {
+        self.0.glyph_ver_advance(glyph_id)
+    }
+
+    /// Returns glyph's vertical side bearing.
+    ///
+    /// This method is affected by variation axes.
+    ///
+    /// Returns `undefined` when `vmtx` table is not present or the glyph is out of bounds.
+    #[wasm_bindgen(js_name = "glyphVerSideBearing")]
+    pub fn glyph_ver_side_bearing(&self, glyph_id: u16) -> Option<i16> {
+        self.0.glyph_ver_side_bearing(glyph_id)
+    }
+
+    /// Returns glyph's vertical origin according to the `VORG` table.
+    ///
+    /// Returns `undefined` when `VORG` table is not present.
+    #[wasm_bindgen(js_name = "glyphYOrigin")]
+    pub fn glyph_y_origin(&self, glyph_id: u16) -> Option<i16> {
+        self.0.glyph_y_origin(glyph_id)
+    }
+
+    /// Returns a localized name, e.g. `getName("Family", ["sv-SE", "en-US"])`.
+    ///
+    /// Locales are BCP 47 tags in the order of preference. They are matched by
+    /// the whole tag first and then by the primary language only. Falls back to
+    /// English and then to the first available name.
+    ///
+    /// Returns `undefined` when `name` table is not present or has no such name.
+    #[wasm_bindgen(js_name = "getName")]
+    pub fn get_name(&self, name_id: NameId, preferred_locales: Vec<String>) -> Option<String> {
+        let preferred_locales: Vec<&str> = preferred_locales.iter().map(String::as_str).collect();
+        self.0.get_name(name_id, &preferred_locales)
+    }
+
+    /// Returns a name by a numeric name ID, preferring English and Windows names.
+    ///
+    /// Unlike `getName`, works with font-specific IDs from 256 and up, e.g. the
+    /// ones referenced by `fvar` axes or stylistic sets.
+    #[wasm_bindgen(js_name = "nameById")]
+    pub fn name_by_id(&self, id: u16) -> Option<String> {
+        self.0.name_by_id(id)
+    }
+
+    /// Returns a family name.
+    ///
+    /// Prefers the typographic family over the legacy one and Windows names over
+    /// Macintosh ones. Returns `undefined` when `name` table is not present.
+    #[wasm_bindgen(getter, js_name = "familyName")]
+    pub fn family_name(&self) -> Option<String> {
+        self.0.family_name()
+    }
+
+    /// Returns a subfamily name.
+    ///
+    /// Prefers the typographic subfamily over the legacy one.
+    #[wasm_bindgen(getter, js_name = "subfamilyName")]
+    pub fn subfamily_name(&self) -> Option<String> {
+        self.0.subfamily_name()
+    }
+
+    /// Returns a full name.
+    #[wasm_bindgen(getter, js_name = "fullName")]
+    pub fn full_name(&self) -> Option<String> {
+        self.0.full_name()
+    }
+
+    /// Returns a PostScript name.
+    #[wasm_bindgen(getter, js_name = "postScriptName")]
+    pub fn post_script_name(&self) -> Option<String> {
+        self.0.post_script_name()
+    }
+
+    /// Returns a typographic family name.
+    #[wasm_bindgen(getter, js_name = "typographicFamily")]
+    pub fn typographic_family(&self) -> Option<String> {
+        self.0.typographic_family()
+    }
+
+    /// Returns a WWS family name.
+    #[wasm_bindgen(getter, js_name = "wwsFamily")]
+    pub fn wws_family(&self) -> Option<String> {
+        self.0.wws_family()
+    }
+
+    /// Returns a bounding box that large enough to enclose any glyph from the face.
+    #[wasm_bindgen(js_name = globalBoundingBox)]
+    pub fn global_bounding_box(&self) -> tables::Rect {
+        self.0.global_bounding_box()
+    }
+
+    /// A [Font Header Table](https://docs.microsoft.com/en-us/typography/opentype/spec/head).
+    #[wasm_bindgen(getter)]
+    pub fn head(&self) -> <tables::head::Table as Tsify>::JsType {
+        head_to_js(self.0.head()).unchecked_into()
+    }
+
+    /// A [Horizontal Header Table](https://docs.microsoft.com/en-us/typography/opentype/spec/hhea).
+    #[wasm_bindgen(getter)]
+    pub fn hhea(&self) -> tables::hhea::Table {
+        *self.0.hhea()
+    }
+
+    /// A [Maximum Profile Table](https://docs.microsoft.com/en-us/typography/opentype/spec/maxp).
+    #[wasm_bindgen(getter)]
+    pub fn maxp(&self) -> tables::maxp::Table {
+        *self.0.maxp()
+    }
+
+    /// A [OS/2 and Windows Metrics Table](https://docs.microsoft.com/en-us/typography/opentype/spec/os2).
+    #[wasm_bindgen(getter)]
+    pub fn os2(&self) -> Option<tables::os2::Table> {
+        self.0.os2().cloned()
+    }
+
+    /// A [Naming Table](https://docs.microsoft.com/en-us/typography/opentype/spec/name).
+    #[wasm_bindgen(getter)]
+    pub fn name(&self) -> Option<tables::name::Table> {
+        self.0.name().cloned()
+    }
+
+    /// A [PostScript Table](https://docs.microsoft.com/en-us/typography/opentype/spec/post).
+    #[wasm_bindgen(getter)]
+    pub fn post(&self) -> Option<tables::post::Table> {
+        self.0.post().cloned()
+    }
+
+    /// A [Vertical Header Table](https://docs.microsoft.com/en-us/typography/opentype/spec/vhea).
+    #[wasm_bindgen(getter)]
+    pub fn vhea(&self) -> Option<tables::vhea::Table> {
+        self.0.vhea().copied()
+    }
+
+    /// A [Vertical Metrics Table](https://docs.microsoft.com/en-us/typography/opentype/spec/vmtx).
+    #[wasm_bindgen(getter)]
+    pub fn vmtx(&self) -> Option<tables::vmtx::Table> {
+        self.0.vmtx().cloned()
+    }
+
+    /// A [Vertical Origin Table](https://docs.microsoft.com/en-us/typography/opentype/spec/vorg).
+    #[wasm_bindgen(getter)]
+    pub fn vorg(&self) -> Option<tables::vorg::Table> {
+        self.0.vorg().cloned()
+    }
+
+    /// All parsed tables.
+    ///
+    /// Parses every table that wasn't accessed yet.
+    #[wasm_bindgen(getter)]
+    pub fn tables(&self) -> <Tables as Tsify>::JsType {
+        let tables = self.0.tables();
+        let value = tables.into_js().unwrap_throw();
+        Reflect::set(&value, &"head".into(), &head_to_js(&tables.head)).unwrap_throw();
+        value
+    }
+}
+
+/// Serializes a `head` table with timestamps converted to `Date` objects.
+fn head_to_js(head: &tables::head::Table) -> JsValue {
+    let value = JsValue::from(head.into_js().unwrap_throw());
+    for (key, timestamp) in [("created", head.created), ("modified", head.modified)] {
+        let date = match timestamp {
+            Some(timestamp) => Date::new(&JsValue::from_f64(timestamp as f64 * 1000.0)).into(),
+            None => JsValue::UNDEFINED,
+        };
+        Reflect::set(&value, &key.into(), &date).unwrap_throw();
+    }
+    value
+}